@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate criterion;
+
+use rand::{thread_rng, Rng};
+
+use argmm::ArgMinMax;
+use arrow_array::Float32Array;
+use criterion::{black_box, Criterion};
+
+/// Every `period`-th slot is null, so the valid runs are `period - 1` long.
+fn get_array_f32(period: usize) -> Float32Array {
+    let mut rng = thread_rng();
+    (0..4096)
+        .map(|i| (i % period != 0).then(|| rng.gen::<f32>()))
+        .collect()
+}
+
+fn min_arrow_f32(c: &mut Criterion) {
+    for period in [2, 16, 64, 256] {
+        let arr = get_array_f32(period);
+        c.bench_function(&format!("argmin_arrow_f32_null_every_{}", period), |b| {
+            b.iter(|| black_box(&arr).argmin())
+        });
+    }
+}
+
+fn minmax_arrow_f32(c: &mut Criterion) {
+    for period in [2, 16, 64, 256] {
+        let arr = get_array_f32(period);
+        c.bench_function(&format!("argminmax_arrow_f32_null_every_{}", period), |b| {
+            b.iter(|| black_box(&arr).argminmax())
+        });
+    }
+}
+
+criterion_group!(benches, min_arrow_f32, minmax_arrow_f32);
+criterion_main!(benches);
@@ -9,7 +9,7 @@ use criterion::{black_box, Criterion};
 
 fn get_array_f32() -> Vec<f32> {
     let rng = thread_rng();
-    let uni = Uniform::new(std::f32::MIN, std::f32::MAX);
+    let uni = Uniform::new(f32::MIN / 4.0, f32::MAX / 4.0);
     rng.sample_iter(uni).take(512).collect()
 }
 
@@ -35,5 +35,16 @@ fn min_f32(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, max_f32, min_f32);
+fn minmax_f32(c: &mut Criterion) {
+    let data = get_array_f32();
+    c.bench_function("simple_argminmax_f32", |b| {
+        b.iter(|| argmm::generic::simple_argminmax(black_box(data.as_slice())))
+    });
+    let data = get_array_f32();
+    c.bench_function("argminmax_simd_f32", |b| {
+        b.iter(|| black_box(data.as_slice().argminmax()))
+    });
+}
+
+criterion_group!(benches, max_f32, min_f32, minmax_f32);
 criterion_main!(benches);
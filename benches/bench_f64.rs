@@ -0,0 +1,50 @@
+#[macro_use]
+extern crate criterion;
+
+use rand::{thread_rng, Rng};
+use rand_distr::Uniform;
+
+use argmm::ArgMinMax;
+use criterion::{black_box, Criterion};
+
+fn get_array_f64() -> Vec<f64> {
+    let rng = thread_rng();
+    let uni = Uniform::new(f64::MIN / 4.0, f64::MAX / 4.0);
+    rng.sample_iter(uni).take(512).collect()
+}
+
+fn max_f64(c: &mut Criterion) {
+    let data = get_array_f64();
+    c.bench_function("simple_argmax_f64", |b| {
+        b.iter(|| argmm::generic::simple_argmax(black_box(data.as_slice())))
+    });
+    let data = get_array_f64();
+    c.bench_function("argmax_simd_f64", |b| {
+        b.iter(|| black_box(data.as_slice().argmax()))
+    });
+}
+
+fn min_f64(c: &mut Criterion) {
+    let data = get_array_f64();
+    c.bench_function("simple_argmin_f64", |b| {
+        b.iter(|| argmm::generic::simple_argmin(black_box(data.as_slice())))
+    });
+    let data = get_array_f64();
+    c.bench_function("argmin_simd_f64", |b| {
+        b.iter(|| black_box(data.as_slice().argmin()))
+    });
+}
+
+fn minmax_f64(c: &mut Criterion) {
+    let data = get_array_f64();
+    c.bench_function("simple_argminmax_f64", |b| {
+        b.iter(|| argmm::generic::simple_argminmax(black_box(data.as_slice())))
+    });
+    let data = get_array_f64();
+    c.bench_function("argminmax_simd_f64", |b| {
+        b.iter(|| black_box(data.as_slice().argminmax()))
+    });
+}
+
+criterion_group!(benches, max_f64, min_f64, minmax_f64);
+criterion_main!(benches);
@@ -9,7 +9,7 @@ use criterion::{black_box, Criterion};
 
 fn get_array_i16() -> Vec<i16> {
     let rng = thread_rng();
-    let uni = Uniform::from(std::i16::MIN..std::i16::MAX);
+    let uni = Uniform::from(i16::MIN..i16::MAX);
     rng.sample_iter(uni).take(512).collect()
 }
 
@@ -35,5 +35,16 @@ fn min_i16(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, max_i16, min_i16);
+fn minmax_i16(c: &mut Criterion) {
+    let data = get_array_i16();
+    c.bench_function("simple_argminmax_i16", |b| {
+        b.iter(|| argmm::generic::simple_argminmax(black_box(data.as_slice())))
+    });
+    let data = get_array_i16();
+    c.bench_function("argminmax_simd_i16", |b| {
+        b.iter(|| black_box(data.as_slice().argminmax()))
+    });
+}
+
+criterion_group!(benches, max_i16, min_i16, minmax_i16);
 criterion_main!(benches);
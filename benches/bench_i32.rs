@@ -9,7 +9,7 @@ use criterion::{black_box, Criterion};
 
 fn get_array_i32() -> Vec<i32> {
     let rng = thread_rng();
-    let uni = Uniform::new(std::i32::MIN, std::i32::MAX);
+    let uni = Uniform::new(i32::MIN, i32::MAX);
     rng.sample_iter(uni).take(512).collect()
 }
 
@@ -35,5 +35,16 @@ fn min_i32(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, max_i32, min_i32);
+fn minmax_i32(c: &mut Criterion) {
+    let data = get_array_i32();
+    c.bench_function("simple_argminmax_i32", |b| {
+        b.iter(|| argmm::generic::simple_argminmax(black_box(data.as_slice())))
+    });
+    let data = get_array_i32();
+    c.bench_function("argminmax_simd_i32", |b| {
+        b.iter(|| black_box(data.as_slice().argminmax()))
+    });
+}
+
+criterion_group!(benches, max_i32, min_i32, minmax_i32);
 criterion_main!(benches);
@@ -0,0 +1,38 @@
+#[macro_use]
+extern crate criterion;
+
+use rand::{thread_rng, Rng};
+use rand_distr::Uniform;
+
+use argmm::{rolling_argmax, rolling_argmin};
+use criterion::{black_box, Criterion};
+
+fn get_array_f32() -> Vec<f32> {
+    let rng = thread_rng();
+    let uni = Uniform::new(f32::MIN / 4.0, f32::MAX / 4.0);
+    rng.sample_iter(uni).take(1 << 16).collect()
+}
+
+// Windows on both sides of the cutoff between the blocked pass and the deque.
+const WINDOWS: [usize; 6] = [4, 64, 1024, 32768, 32769, 60000];
+
+fn rolling_min_f32(c: &mut Criterion) {
+    let data = get_array_f32();
+    for w in WINDOWS {
+        c.bench_function(&format!("rolling_argmin_f32_w{}", w), |b| {
+            b.iter(|| rolling_argmin(black_box(data.as_slice()), w))
+        });
+    }
+}
+
+fn rolling_max_f32(c: &mut Criterion) {
+    let data = get_array_f32();
+    for w in WINDOWS {
+        c.bench_function(&format!("rolling_argmax_f32_w{}", w), |b| {
+            b.iter(|| rolling_argmax(black_box(data.as_slice()), w))
+        });
+    }
+}
+
+criterion_group!(benches, rolling_min_f32, rolling_max_f32);
+criterion_main!(benches);
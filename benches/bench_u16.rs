@@ -9,7 +9,7 @@ use criterion::{black_box, Criterion};
 
 fn get_array_u16() -> Vec<u16> {
     let rng = thread_rng();
-    let uni = Uniform::from(std::u16::MIN..std::u16::MAX);
+    let uni = Uniform::from(u16::MIN..u16::MAX);
     rng.sample_iter(uni).take(512).collect()
 }
 
@@ -35,5 +35,16 @@ fn min_u16(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, max_u16, min_u16);
+fn minmax_u16(c: &mut Criterion) {
+    let data = get_array_u16();
+    c.bench_function("simple_argminmax_u16", |b| {
+        b.iter(|| argmm::generic::simple_argminmax(black_box(data.as_slice())))
+    });
+    let data = get_array_u16();
+    c.bench_function("argminmax_simd_u16", |b| {
+        b.iter(|| black_box(data.as_slice().argminmax()))
+    });
+}
+
+criterion_group!(benches, max_u16, min_u16, minmax_u16);
 criterion_main!(benches);
@@ -9,7 +9,7 @@ use criterion::{black_box, Criterion};
 
 fn get_array_u8() -> Vec<u8> {
     let rng = thread_rng();
-    let uni = Uniform::from(std::u8::MIN..std::u8::MAX);
+    let uni = Uniform::from(u8::MIN..u8::MAX);
     rng.sample_iter(uni).take(512).collect()
 }
 
@@ -35,5 +35,16 @@ fn min_u8(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, max_u8, min_u8);
+fn minmax_u8(c: &mut Criterion) {
+    let data = get_array_u8();
+    c.bench_function("simple_argminmax_u8", |b| {
+        b.iter(|| argmm::generic::simple_argminmax(black_box(data.as_slice())))
+    });
+    let data = get_array_u8();
+    c.bench_function("argminmax_simd_u8", |b| {
+        b.iter(|| black_box(data.as_slice().argminmax()))
+    });
+}
+
+criterion_group!(benches, max_u8, min_u8, minmax_u8);
 criterion_main!(benches);
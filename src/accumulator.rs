@@ -0,0 +1,161 @@
+use crate::task::{merge_max, merge_min};
+use crate::ArgMinMax;
+
+/// Argmin or argmax of a stream that arrives in chunks, for data that never
+/// sits in memory as a whole. Every chunk goes through the SIMD kernels and
+/// indices count from the start of the stream. As with [`ArgMinMax`], the
+/// first index wins on ties.
+///
+/// Accumulators that saw consecutive parts of the same stream, for example on
+/// different threads, can be combined with [`merge`](Self::merge).
+#[derive(Clone, Debug)]
+pub struct ArgMinMaxAccumulator<T> {
+    best: Option<(T, usize)>,
+    offset: usize,
+    largest: bool,
+}
+
+impl<T> ArgMinMaxAccumulator<T>
+where
+    T: Copy + PartialOrd,
+    [T]: ArgMinMax<Value = T>,
+{
+    /// Accumulator for the smallest value.
+    pub fn argmin() -> Self {
+        ArgMinMaxAccumulator {
+            best: None,
+            offset: 0,
+            largest: false,
+        }
+    }
+
+    /// Accumulator for the largest value.
+    pub fn argmax() -> Self {
+        ArgMinMaxAccumulator {
+            best: None,
+            offset: 0,
+            largest: true,
+        }
+    }
+
+    /// Number of elements seen so far, which is where the next chunk starts.
+    pub fn len(&self) -> usize {
+        self.offset
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Feeds the next chunk of the stream.
+    pub fn update(&mut self, chunk: &[T]) {
+        let found = if self.largest {
+            chunk.max_with_index()
+        } else {
+            chunk.min_with_index()
+        };
+        if let Some((value, index)) = found {
+            self.push((value, self.offset + index));
+        }
+        self.offset += chunk.len();
+    }
+
+    /// Appends the elements seen by `other`, which must come right after the
+    /// ones seen by `self` in the stream.
+    ///
+    /// # Panics
+    ///
+    /// When one accumulator looks for the minimum and the other for the maximum.
+    pub fn merge(&mut self, other: Self) {
+        assert_eq!(
+            self.largest, other.largest,
+            "cannot merge an argmin accumulator with an argmax one"
+        );
+        if let Some((value, index)) = other.best {
+            self.push((value, self.offset + index));
+        }
+        self.offset += other.offset;
+    }
+
+    /// Index and value of the extremum, or `None` when no element was seen.
+    pub fn finish(self) -> Option<(usize, T)> {
+        self.best.map(|(value, index)| (index, value))
+    }
+
+    #[inline]
+    fn push(&mut self, candidate: (T, usize)) {
+        self.best = Some(match self.best {
+            None => candidate,
+            Some(best) if self.largest => merge_max(best, candidate),
+            Some(best) => merge_min(best, candidate),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArgMinMaxAccumulator;
+    use crate::ArgMinMax;
+
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_chunked_updates_match_whole_input() {
+        macro_rules! check {
+            ($($t:ty),*) => {$(
+                let data: Vec<$t> = (0..10_007).map(|_| thread_rng().gen()).collect();
+                for chunk_len in [1, 13, 256, 4_099, 20_000] {
+                    let mut low = ArgMinMaxAccumulator::argmin();
+                    let mut high = ArgMinMaxAccumulator::argmax();
+                    for chunk in data.chunks(chunk_len) {
+                        low.update(chunk);
+                        high.update(chunk);
+                    }
+                    assert_eq!(low.len(), data.len());
+                    assert_eq!(low.finish(), data.min_with_index().map(|(v, i)| (i, v)));
+                    assert_eq!(high.finish(), data.max_with_index().map(|(v, i)| (i, v)));
+                }
+            )*};
+        }
+        check!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+    }
+
+    #[test]
+    fn test_merge_keeps_first_occurrence() {
+        let data = [4u8, 1, 9, 1, 9, 3, 1, 9];
+        let parts: Vec<ArgMinMaxAccumulator<u8>> = data
+            .chunks(3)
+            .map(|chunk| {
+                let mut acc = ArgMinMaxAccumulator::argmax();
+                acc.update(chunk);
+                acc
+            })
+            .collect();
+
+        let mut merged = ArgMinMaxAccumulator::argmax();
+        for part in parts.iter().cloned() {
+            merged.merge(part);
+        }
+        assert_eq!(merged.len(), 8);
+        assert_eq!(merged.finish(), Some((2, 9)));
+
+        // Merging in pairs gives the same answer.
+        let mut right = parts[1].clone();
+        right.merge(parts[2].clone());
+        let mut left = parts[0].clone();
+        left.update(&[]);
+        left.merge(right);
+        assert_eq!(left.finish(), Some((2, 9)));
+
+        let empty = ArgMinMaxAccumulator::<f32>::argmin();
+        assert!(empty.is_empty());
+        assert_eq!(empty.finish(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot merge")]
+    fn test_merge_rejects_opposite_direction() {
+        let mut low = ArgMinMaxAccumulator::<i32>::argmin();
+        low.merge(ArgMinMaxAccumulator::argmax());
+    }
+}
@@ -0,0 +1,179 @@
+use ndarray::{Array, ArrayBase, Axis, Data, Ix1, RemoveAxis};
+
+use crate::generic::{simple_argmax_iter, simple_argmin_iter};
+use crate::ArgMinMax;
+
+/// Contiguous arrays go through the slice kernels, strided or reversed ones are
+/// scanned element by element.
+impl<S, T> ArgMinMax for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = T>,
+    T: Copy + PartialOrd,
+    [T]: ArgMinMax<Value = T>,
+{
+    type Value = T;
+
+    fn argmin(&self) -> Option<usize> {
+        match self.as_slice() {
+            Some(slice) => slice.argmin(),
+            None => simple_argmin_iter(self),
+        }
+    }
+
+    fn argmax(&self) -> Option<usize> {
+        match self.as_slice() {
+            Some(slice) => slice.argmax(),
+            None => simple_argmax_iter(self),
+        }
+    }
+
+    fn argminmax(&self) -> Option<(usize, usize)> {
+        match self.as_slice() {
+            Some(slice) => slice.argminmax(),
+            None => Some((simple_argmin_iter(self)?, simple_argmax_iter(self)?)),
+        }
+    }
+
+    fn min_with_index(&self) -> Option<(T, usize)> {
+        match self.as_slice() {
+            Some(slice) => slice.min_with_index(),
+            None => self.argmin().map(|index| (self[index], index)),
+        }
+    }
+
+    fn max_with_index(&self) -> Option<(T, usize)> {
+        match self.as_slice() {
+            Some(slice) => slice.max_with_index(),
+            None => self.argmax().map(|index| (self[index], index)),
+        }
+    }
+
+    fn minmax_with_indices(&self) -> Option<((T, usize), (T, usize))> {
+        match self.as_slice() {
+            Some(slice) => slice.minmax_with_indices(),
+            None => self
+                .argminmax()
+                .map(|(min, max)| ((self[min], min), (self[max], max))),
+        }
+    }
+}
+
+/// Argmin/argmax of every lane along one axis of an n-dimensional array, for
+/// example the column holding the extremum of each row of a 2-D array with
+/// `Axis(1)`. The result has the shape of the array with `axis` removed.
+pub trait ArgMinMaxAxis {
+    type Output;
+
+    /// # Panics
+    ///
+    /// When `axis` is out of bounds or has length zero.
+    fn argmin_axis(&self, axis: Axis) -> Self::Output;
+
+    /// # Panics
+    ///
+    /// When `axis` is out of bounds or has length zero.
+    fn argmax_axis(&self, axis: Axis) -> Self::Output;
+}
+
+impl<S, T, D> ArgMinMaxAxis for ArrayBase<S, D>
+where
+    S: Data<Elem = T>,
+    T: Copy + PartialOrd,
+    [T]: ArgMinMax<Value = T>,
+    D: RemoveAxis,
+{
+    type Output = Array<usize, D::Smaller>;
+
+    fn argmin_axis(&self, axis: Axis) -> Self::Output {
+        self.map_axis(axis, |lane| {
+            lane.argmin().expect("argmin_axis over an empty axis")
+        })
+    }
+
+    fn argmax_axis(&self, axis: Axis) -> Self::Output {
+        self.map_axis(axis, |lane| {
+            lane.argmax().expect("argmax_axis over an empty axis")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArgMinMaxAxis;
+    use crate::generic::{simple_argmax, simple_argmin};
+    use crate::ArgMinMax;
+
+    use ndarray::{s, Array, Array1, Array2, Axis, IxDyn};
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_one_dimensional_arrays_match_slices() {
+        let data: Vec<f32> = (0..1027).map(|_| thread_rng().gen()).collect();
+        let arr = Array1::from(data.clone());
+        assert_eq!(arr.argmin(), data.argmin());
+        assert_eq!(arr.argmax(), data.argmax());
+        assert_eq!(arr.argminmax(), data.argminmax());
+
+        // Strided and reversed views are not contiguous.
+        for view in [arr.slice(s![..;3]), arr.slice(s![..;-1])] {
+            let copy = view.to_vec();
+            assert_eq!(view.argmin(), Some(simple_argmin(&copy)));
+            assert_eq!(view.argmax(), Some(simple_argmax(&copy)));
+            assert_eq!(
+                view.argminmax(),
+                Some((simple_argmin(&copy), simple_argmax(&copy)))
+            );
+        }
+
+        assert_eq!(Array1::<i32>::zeros(0).argmin(), None);
+        assert_eq!(Array1::<i32>::zeros(0).slice(s![..;-1]).argminmax(), None);
+    }
+
+    #[test]
+    fn test_axis_reductions_on_two_dimensional_arrays() {
+        let (rows, cols) = (37, 45);
+        let data: Vec<i16> = (0..rows * cols).map(|_| thread_rng().gen()).collect();
+        let arr = Array2::from_shape_vec((rows, cols), data.clone()).unwrap();
+
+        let row_min: Vec<usize> = data.chunks(cols).map(simple_argmin).collect();
+        let row_max: Vec<usize> = data.chunks(cols).map(simple_argmax).collect();
+        assert_eq!(arr.argmin_axis(Axis(1)).to_vec(), row_min);
+        assert_eq!(arr.argmax_axis(Axis(1)).to_vec(), row_max);
+
+        let column = |c: usize| (0..rows).map(|r| data[r * cols + c]).collect::<Vec<_>>();
+        let col_min: Vec<usize> = (0..cols).map(|c| simple_argmin(&column(c))).collect();
+        let col_max: Vec<usize> = (0..cols).map(|c| simple_argmax(&column(c))).collect();
+        assert_eq!(arr.argmin_axis(Axis(0)).to_vec(), col_min);
+        assert_eq!(arr.argmax_axis(Axis(0)).to_vec(), col_max);
+
+        // Transposing swaps which lanes are contiguous.
+        assert_eq!(arr.t().argmin_axis(Axis(0)).to_vec(), row_min);
+        assert_eq!(arr.t().argmax_axis(Axis(1)).to_vec(), col_max);
+    }
+
+    #[test]
+    fn test_axis_reductions_on_dynamic_arrays() {
+        let arr = Array::from_shape_fn(IxDyn(&[3, 4, 5]), |d| {
+            ((d[0] * 7 + d[1] * 3 + d[2] * 11) % 13) as u8
+        });
+        let argmin = arr.argmin_axis(Axis(2));
+        assert_eq!(argmin.shape(), &[3, 4]);
+        for ((i, j), &k) in argmin.indexed_iter().map(|(d, k)| ((d[0], d[1]), k)) {
+            let lane = arr.slice(s![i, j, ..]).to_vec();
+            assert_eq!(k, simple_argmin(&lane));
+        }
+
+        let argmax = arr.argmax_axis(Axis(0));
+        assert_eq!(argmax.shape(), &[4, 5]);
+        for ((j, k), &i) in argmax.indexed_iter().map(|(d, i)| ((d[0], d[1]), i)) {
+            let lane = arr.slice(s![.., j, k]).to_vec();
+            assert_eq!(i, simple_argmax(&lane));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "empty axis")]
+    fn test_axis_reduction_panics_on_empty_axis() {
+        Array2::<f64>::zeros((3, 0)).argmin_axis(Axis(1));
+    }
+}
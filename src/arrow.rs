@@ -0,0 +1,275 @@
+use arrow_array::types::ArrowPrimitiveType;
+use arrow_array::{Array, PrimitiveArray};
+use arrow_buffer::NullBuffer;
+use std::borrow::Cow;
+
+use crate::{ArgMinMax, Mask, MaskedArgMinMax};
+
+/// Valid runs shorter than this on average are scanned with the masked kernels
+/// over the validity bitmap instead of one slice kernel call per run.
+const SHORT_RUN: usize = 64;
+
+/// Indices count every slot of the array, null ones included, but null slots
+/// are never returned. `None` means the array is empty or entirely null. An
+/// array without nulls is handed to the slice kernels as a whole.
+impl<T> ArgMinMax for PrimitiveArray<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: PartialOrd,
+    [T::Native]: ArgMinMax<Value = T::Native> + MaskedArgMinMax,
+{
+    type Value = T::Native;
+
+    fn argmin(&self) -> Option<usize> {
+        self.min_with_index().map(|(_, index)| index)
+    }
+
+    fn argmax(&self) -> Option<usize> {
+        self.max_with_index().map(|(_, index)| index)
+    }
+
+    fn argminmax(&self) -> Option<(usize, usize)> {
+        self.minmax_with_indices()
+            .map(|((_, min), (_, max))| (min, max))
+    }
+
+    fn min_with_index(&self) -> Option<(T::Native, usize)> {
+        match self.nulls().filter(|nulls| nulls.null_count() > 0) {
+            None => self.values().min_with_index(),
+            Some(nulls) if short_runs(nulls) => self
+                .values()
+                .min_with_index_masked(Mask::Bits(&validity_bits(nulls))),
+            Some(nulls) => masked_min(self.values(), nulls),
+        }
+    }
+
+    fn max_with_index(&self) -> Option<(T::Native, usize)> {
+        match self.nulls().filter(|nulls| nulls.null_count() > 0) {
+            None => self.values().max_with_index(),
+            Some(nulls) if short_runs(nulls) => self
+                .values()
+                .max_with_index_masked(Mask::Bits(&validity_bits(nulls))),
+            Some(nulls) => masked_max(self.values(), nulls),
+        }
+    }
+
+    fn minmax_with_indices(&self) -> Option<((T::Native, usize), (T::Native, usize))> {
+        match self.nulls().filter(|nulls| nulls.null_count() > 0) {
+            None => self.values().minmax_with_indices(),
+            Some(nulls) if short_runs(nulls) => {
+                let bits = validity_bits(nulls);
+                let min = self.values().min_with_index_masked(Mask::Bits(&bits));
+                min.zip(self.values().max_with_index_masked(Mask::Bits(&bits)))
+            }
+            Some(nulls) => masked_minmax(self.values(), nulls),
+        }
+    }
+}
+
+/// Whether the valid slots are split into runs too short to be worth a slice
+/// kernel call each, as with alternating nulls.
+fn short_runs(nulls: &NullBuffer) -> bool {
+    let runs = nulls.valid_slices().count();
+    nulls.len() - nulls.null_count() < runs * SHORT_RUN
+}
+
+/// The validity bitmap in the [`Mask::Bits`] layout, bit 0 of the first word
+/// being slot 0. The buffer is borrowed when it starts word aligned at slot 0,
+/// and otherwise copied with the offset shifted out.
+fn validity_bits(nulls: &NullBuffer) -> Cow<'_, [u64]> {
+    if cfg!(target_endian = "little") && nulls.offset() == 0 {
+        // Any bit pattern is a valid u64.
+        let (prefix, words, _) = unsafe { nulls.validity().align_to::<u64>() };
+        if prefix.is_empty() && words.len() >= nulls.len().div_ceil(64) {
+            return Cow::Borrowed(words);
+        }
+    }
+    Cow::Owned(nulls.inner().bit_chunks().iter_padded().collect())
+}
+
+// Each run of valid slots goes through the slice kernels, and the runs are
+// merged in order with a strict comparison so the first index wins on ties.
+
+fn masked_min<T>(values: &[T], nulls: &NullBuffer) -> Option<(T, usize)>
+where
+    T: Copy + PartialOrd,
+    [T]: ArgMinMax<Value = T>,
+{
+    let mut low: Option<(T, usize)> = None;
+    for (start, end) in nulls.valid_slices() {
+        let (min, index) = values[start..end].min_with_index()?;
+        if low.is_none_or(|(value, _)| min < value) {
+            low = Some((min, start + index));
+        }
+    }
+    low
+}
+
+fn masked_max<T>(values: &[T], nulls: &NullBuffer) -> Option<(T, usize)>
+where
+    T: Copy + PartialOrd,
+    [T]: ArgMinMax<Value = T>,
+{
+    let mut high: Option<(T, usize)> = None;
+    for (start, end) in nulls.valid_slices() {
+        let (max, index) = values[start..end].max_with_index()?;
+        if high.is_none_or(|(value, _)| max > value) {
+            high = Some((max, start + index));
+        }
+    }
+    high
+}
+
+fn masked_minmax<T>(values: &[T], nulls: &NullBuffer) -> Option<((T, usize), (T, usize))>
+where
+    T: Copy + PartialOrd,
+    [T]: ArgMinMax<Value = T>,
+{
+    let mut extremes: Option<((T, usize), (T, usize))> = None;
+    for (start, end) in nulls.valid_slices() {
+        let ((min, min_index), (max, max_index)) = values[start..end].minmax_with_indices()?;
+        let (min, max) = ((min, start + min_index), (max, start + max_index));
+        extremes = Some(match extremes {
+            None => (min, max),
+            Some((low, high)) => (
+                if min.0 < low.0 { min } else { low },
+                if max.0 > high.0 { max } else { high },
+            ),
+        });
+    }
+    extremes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generic::{simple_argmax_iter, simple_argmin_iter};
+    use crate::ArgMinMax;
+
+    use arrow_array::{
+        Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, UInt16Array, UInt32Array,
+        UInt8Array,
+    };
+    use rand::{thread_rng, Rng};
+
+    fn valid_values<T: Copy>(values: &[Option<T>]) -> impl Iterator<Item = (usize, T)> + '_ {
+        values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, value)| value.map(|value| (i, value)))
+    }
+
+    /// Index of the first extreme among the non-null values, by brute force.
+    fn expected_argmin<T: Copy + PartialOrd>(values: &[Option<T>]) -> Option<usize> {
+        let valid: Vec<(usize, T)> = valid_values(values).collect();
+        let position = simple_argmin_iter(valid.iter().map(|(_, value)| value))?;
+        Some(valid[position].0)
+    }
+
+    fn expected_argmax<T: Copy + PartialOrd>(values: &[Option<T>]) -> Option<usize> {
+        let valid: Vec<(usize, T)> = valid_values(values).collect();
+        let position = simple_argmax_iter(valid.iter().map(|(_, value)| value))?;
+        Some(valid[position].0)
+    }
+
+    #[test]
+    fn test_arrays_without_nulls_match_slices() {
+        let data: Vec<f32> = (0..1027).map(|_| thread_rng().gen()).collect();
+        let arr = Float32Array::from(data.clone());
+        assert_eq!(arr.argmin(), data.argmin());
+        assert_eq!(arr.argmax(), data.argmax());
+        assert_eq!(arr.argminmax(), data.argminmax());
+
+        // A sliced array keeps its own indices.
+        let sliced = arr.slice(100, 500);
+        assert_eq!(sliced.argmin(), data[100..600].argmin());
+        assert_eq!(Int32Array::from(Vec::<i32>::new()).argmax(), None);
+    }
+
+    #[test]
+    fn test_null_slots_are_skipped() {
+        // Nulls cover the true extremes, which must not be returned.
+        let arr = Int16Array::from(vec![Some(5), None, Some(-3), Some(9), None, Some(-3)]);
+        assert_eq!(arr.argmin(), Some(2));
+        assert_eq!(arr.argmax(), Some(3));
+        assert_eq!(arr.argminmax(), Some((2, 3)));
+
+        let all_null = UInt8Array::from(vec![None, None, None]);
+        assert_eq!(all_null.argmin(), None);
+        assert_eq!(all_null.argminmax(), None);
+    }
+
+    #[test]
+    fn test_partially_null_arrays_match_brute_force() {
+        let mut rng = thread_rng();
+        for &density in &[0.01, 0.5, 0.99] {
+            let values: Vec<Option<u16>> = (0..3001)
+                .map(|_| rng.gen_bool(density).then(|| rng.gen_range(0..500)))
+                .collect();
+            let arr = UInt16Array::from(values.clone());
+            assert_eq!(arr.argmin(), expected_argmin(&values));
+            assert_eq!(arr.argmax(), expected_argmax(&values));
+            let expected = expected_argmin(&values).zip(expected_argmax(&values));
+            assert_eq!(arr.argminmax(), expected);
+
+            let sliced = arr.slice(77, 2000);
+            assert_eq!(sliced.argmin(), expected_argmin(&values[77..2077]));
+            assert_eq!(sliced.argmax(), expected_argmax(&values[77..2077]));
+
+            let with_value = |index: usize| (values[index].unwrap(), index);
+            let min = expected_argmin(&values).map(with_value);
+            let max = expected_argmax(&values).map(with_value);
+            assert_eq!(arr.minmax_with_indices(), min.zip(max));
+        }
+
+        let values: Vec<Option<f64>> = (0..777).map(|i| (i % 3 != 0).then(|| rng.gen())).collect();
+        let arr = Float64Array::from(values.clone());
+        assert_eq!(arr.argmin(), expected_argmin(&values));
+        assert_eq!(arr.argmax(), expected_argmax(&values));
+
+        let values: Vec<Option<i8>> = (0..777).map(|i| (i % 5 != 1).then(|| rng.gen())).collect();
+        let arr = Int8Array::from(values.clone());
+        assert_eq!(arr.argmin(), expected_argmin(&values));
+        assert_eq!(arr.argmax(), expected_argmax(&values));
+    }
+
+    #[test]
+    fn test_alternating_nulls_match_brute_force() {
+        // Runs of one or two valid slots go through the bitmap kernels.
+        let mut rng = thread_rng();
+        for period in [2, 3] {
+            let values: Vec<Option<f32>> = (0..2051)
+                .map(|i| (i % period != 0).then(|| rng.gen_range(0..64) as f32))
+                .collect();
+            let arr = Float32Array::from(values.clone());
+            assert_eq!(arr.argmin(), expected_argmin(&values));
+            assert_eq!(arr.argmax(), expected_argmax(&values));
+            let expected = expected_argmin(&values).zip(expected_argmax(&values));
+            assert_eq!(arr.argminmax(), expected);
+
+            let with_value = |index: usize| (values[index].unwrap(), index);
+            let min = expected_argmin(&values).map(with_value);
+            let max = expected_argmax(&values).map(with_value);
+            assert_eq!(arr.min_with_index(), min);
+            assert_eq!(arr.max_with_index(), max);
+            assert_eq!(arr.minmax_with_indices(), min.zip(max));
+
+            // Offsets that are not a whole word have to be shifted out.
+            for offset in [1, 64, 67] {
+                let sliced = arr.slice(offset, 1900);
+                let window = &values[offset..offset + 1900];
+                assert_eq!(sliced.argmin(), expected_argmin(window));
+                assert_eq!(sliced.argmax(), expected_argmax(window));
+            }
+        }
+
+        let values: Vec<Option<u32>> = (0..999).map(|i| (i % 2 == 1).then(|| rng.gen())).collect();
+        let arr = UInt32Array::from(values.clone());
+        assert_eq!(arr.argmin(), expected_argmin(&values));
+        assert_eq!(arr.argmax(), expected_argmax(&values));
+
+        let values: Vec<Option<i16>> = (0..999).map(|i| (i % 2 == 0).then(|| rng.gen())).collect();
+        let arr = Int16Array::from(values.clone());
+        assert_eq!(arr.argmin(), expected_argmin(&values));
+        assert_eq!(arr.argmax(), expected_argmax(&values));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::ArgMinMax;
+
+#[cfg(not(target_arch = "x86_64"))]
+use crate::generic::{simple_argmax_columns, simple_argmin_columns};
+#[cfg(target_arch = "x86_64")]
+use crate::simd_f32::{argmax_f32_columns, argmin_f32_columns};
+
+/// The direction a row-major matrix is reduced in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    /// One index per row: the column holding the row's extremum.
+    Rows,
+    /// One index per column: the row holding the column's extremum.
+    Columns,
+}
+
+/// Argmin of every row or column of a `rows` x `cols` matrix stored row by row
+/// in `data`. Ties go to the first column or row. Returns an empty vector when
+/// the matrix has no elements.
+///
+/// # Panics
+///
+/// When `cols` is zero or `data.len()` is not `rows * cols`.
+pub fn argmin_f32(data: &[f32], rows: usize, cols: usize, axis: Axis) -> Vec<usize> {
+    check_shape(data, rows, cols);
+    if data.is_empty() {
+        return Vec::new();
+    }
+
+    match axis {
+        Axis::Rows => data
+            .chunks_exact(cols)
+            .map(|row| row.argmin().unwrap())
+            .collect(),
+        #[cfg(target_arch = "x86_64")]
+        Axis::Columns => argmin_f32_columns(data, cols),
+        #[cfg(not(target_arch = "x86_64"))]
+        Axis::Columns => simple_argmin_columns(data, cols),
+    }
+}
+
+/// Argmax of every row or column of a `rows` x `cols` matrix stored row by row
+/// in `data`. Ties go to the first column or row. Returns an empty vector when
+/// the matrix has no elements.
+///
+/// # Panics
+///
+/// When `cols` is zero or `data.len()` is not `rows * cols`.
+pub fn argmax_f32(data: &[f32], rows: usize, cols: usize, axis: Axis) -> Vec<usize> {
+    check_shape(data, rows, cols);
+    if data.is_empty() {
+        return Vec::new();
+    }
+
+    match axis {
+        Axis::Rows => data
+            .chunks_exact(cols)
+            .map(|row| row.argmax().unwrap())
+            .collect(),
+        #[cfg(target_arch = "x86_64")]
+        Axis::Columns => argmax_f32_columns(data, cols),
+        #[cfg(not(target_arch = "x86_64"))]
+        Axis::Columns => simple_argmax_columns(data, cols),
+    }
+}
+
+#[inline]
+fn check_shape(data: &[f32], rows: usize, cols: usize) {
+    assert!(cols > 0, "matrix must have at least one column");
+    assert_eq!(
+        Some(data.len()),
+        rows.checked_mul(cols),
+        "data does not hold a {}x{} matrix",
+        rows,
+        cols
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{argmax_f32, argmin_f32, Axis};
+    use crate::generic::{simple_argmax, simple_argmin};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_matrix_f32(rows: usize, cols: usize) -> Vec<f32> {
+        let rng = thread_rng();
+        let uni = Uniform::new(-1.0, 1.0);
+        rng.sample_iter(uni).take(rows * cols).collect()
+    }
+
+    fn column(data: &[f32], cols: usize, column: usize) -> Vec<f32> {
+        data.iter().skip(column).step_by(cols).copied().collect()
+    }
+
+    #[test]
+    fn test_axis_reductions_match_per_row_and_per_column_scans() {
+        for (rows, cols) in [(1, 1), (1, 37), (37, 1), (9, 4), (100, 10), (33, 67)] {
+            let data = get_matrix_f32(rows, cols);
+
+            let row_min: Vec<usize> = data.chunks(cols).map(simple_argmin).collect();
+            let row_max: Vec<usize> = data.chunks(cols).map(simple_argmax).collect();
+            assert_eq!(argmin_f32(&data, rows, cols, Axis::Rows), row_min);
+            assert_eq!(argmax_f32(&data, rows, cols, Axis::Rows), row_max);
+
+            let col_min: Vec<usize> = (0..cols)
+                .map(|c| simple_argmin(&column(&data, cols, c)))
+                .collect();
+            let col_max: Vec<usize> = (0..cols)
+                .map(|c| simple_argmax(&column(&data, cols, c)))
+                .collect();
+            assert_eq!(argmin_f32(&data, rows, cols, Axis::Columns), col_min);
+            assert_eq!(argmax_f32(&data, rows, cols, Axis::Columns), col_max);
+        }
+    }
+
+    #[test]
+    fn test_first_row_wins_ties_in_columns() {
+        let data = [
+            1.0, 5.0, 3.0, //
+            0.0, 5.0, 3.0, //
+            0.0, 2.0, 3.0, //
+        ];
+
+        assert_eq!(argmin_f32(&data, 3, 3, Axis::Columns), vec![1, 2, 0]);
+        assert_eq!(argmax_f32(&data, 3, 3, Axis::Columns), vec![0, 0, 0]);
+        assert_eq!(argmin_f32(&data, 3, 3, Axis::Rows), vec![0, 0, 0]);
+        assert_eq!(argmax_f32(&data, 3, 3, Axis::Rows), vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn test_empty_matrix_returns_no_indices() {
+        assert!(argmin_f32(&[], 0, 5, Axis::Columns).is_empty());
+        assert!(argmax_f32(&[], 0, 5, Axis::Rows).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one column")]
+    fn test_zero_columns_panics() {
+        argmin_f32(&[], 4, 0, Axis::Columns);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shape_mismatch_panics() {
+        argmax_f32(&[1.0, 2.0, 3.0], 2, 2, Axis::Rows);
+    }
+}
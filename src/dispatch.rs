@@ -0,0 +1,71 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The widest instruction set the kernels are dispatched to, detected at runtime.
+///
+/// The `f32` and `f64` kernels only need SSE2, which every x86_64 CPU has, so
+/// they keep using SIMD even at `SimdLevel::Scalar`. On aarch64 the level is
+/// always `SimdLevel::Neon`, the ordering only matters between the x86 levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SimdLevel {
+    Scalar,
+    Sse41,
+    Avx2,
+    Avx512,
+    Neon,
+}
+
+static SIMD_LEVEL: AtomicU8 = AtomicU8::new(u8::MAX);
+
+/// Returns the detected [`SimdLevel`]. Detection runs on the first call and
+/// the result is cached for the lifetime of the process.
+#[inline]
+pub fn simd_level() -> SimdLevel {
+    match SIMD_LEVEL.load(Ordering::Relaxed) {
+        0 => SimdLevel::Scalar,
+        1 => SimdLevel::Sse41,
+        2 => SimdLevel::Avx2,
+        3 => SimdLevel::Avx512,
+        4 => SimdLevel::Neon,
+        _ => {
+            let level = detect();
+            SIMD_LEVEL.store(level as u8, Ordering::Relaxed);
+            level
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn detect() -> SimdLevel {
+    if is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw") {
+        SimdLevel::Avx512
+    } else if is_x86_feature_detected!("avx2") {
+        SimdLevel::Avx2
+    } else if is_x86_feature_detected!("sse4.1") {
+        SimdLevel::Sse41
+    } else {
+        SimdLevel::Scalar
+    }
+}
+
+/// NEON is part of the base aarch64 instruction set, so there is nothing to detect.
+#[cfg(target_arch = "aarch64")]
+fn detect() -> SimdLevel {
+    SimdLevel::Neon
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn detect() -> SimdLevel {
+    SimdLevel::Scalar
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect, simd_level};
+
+    #[test]
+    fn test_detected_level_is_cached() {
+        let level = simd_level();
+        assert_eq!(level, detect());
+        assert_eq!(level, simd_level());
+    }
+}
@@ -1,3 +1,7 @@
+use crate::Mask;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
 #[inline]
 pub fn simple_argmin<T: Copy + PartialOrd>(arr: &[T]) -> usize {
     let mut low_index = 0usize;
@@ -23,3 +27,386 @@ pub fn simple_argmax<T: Copy + PartialOrd>(arr: &[T]) -> usize {
     }
     high_index
 }
+
+/// Like [`simple_argmin`], but ties go to the last index.
+#[inline]
+pub fn simple_argmin_last<T: Copy + PartialOrd>(arr: &[T]) -> usize {
+    let mut low_index = 0usize;
+    let mut low = arr[low_index];
+    for (i, item) in arr.iter().enumerate() {
+        if *item <= low {
+            low = *item;
+            low_index = i;
+        }
+    }
+    low_index
+}
+
+/// Like [`simple_argmax`], but ties go to the last index.
+#[inline]
+pub fn simple_argmax_last<T: Copy + PartialOrd>(arr: &[T]) -> usize {
+    let mut high_index = 0usize;
+    let mut high = arr[high_index];
+    for (i, item) in arr.iter().enumerate() {
+        if *item >= high {
+            high = *item;
+            high_index = i;
+        }
+    }
+    high_index
+}
+
+#[inline]
+pub fn simple_argminmax<T: Copy + PartialOrd>(arr: &[T]) -> (usize, usize) {
+    let mut low_index = 0usize;
+    let mut high_index = 0usize;
+    let mut low = arr[low_index];
+    let mut high = arr[high_index];
+    for (i, item) in arr.iter().enumerate() {
+        if *item < low {
+            low = *item;
+            low_index = i;
+        } else if *item > high {
+            high = *item;
+            high_index = i;
+        }
+    }
+    (low_index, high_index)
+}
+
+#[inline]
+pub fn simple_nanargmin<T: Copy + PartialOrd>(arr: &[T]) -> Option<usize> {
+    let mut low: Option<(T, usize)> = None;
+    for (i, item) in arr.iter().enumerate() {
+        if item.partial_cmp(item).is_none() {
+            continue;
+        }
+        if low.is_none_or(|(value, _)| *item < value) {
+            low = Some((*item, i));
+        }
+    }
+    low.map(|(_, index)| index)
+}
+
+#[inline]
+pub fn simple_nanargmax<T: Copy + PartialOrd>(arr: &[T]) -> Option<usize> {
+    let mut high: Option<(T, usize)> = None;
+    for (i, item) in arr.iter().enumerate() {
+        if item.partial_cmp(item).is_none() {
+            continue;
+        }
+        if high.is_none_or(|(value, _)| *item > value) {
+            high = Some((*item, i));
+        }
+    }
+    high.map(|(_, index)| index)
+}
+
+#[inline]
+pub fn simple_first_nan<T: PartialOrd>(arr: &[T]) -> Option<usize> {
+    arr.iter().position(|item| item.partial_cmp(item).is_none())
+}
+
+/// Index of the first smallest item of `iter`, or `None` when it is empty.
+#[inline]
+pub fn simple_argmin_iter<'a, T: 'a + Copy + PartialOrd>(
+    iter: impl IntoIterator<Item = &'a T>,
+) -> Option<usize> {
+    let mut low: Option<(T, usize)> = None;
+    for (i, item) in iter.into_iter().enumerate() {
+        if low.is_none_or(|(value, _)| *item < value) {
+            low = Some((*item, i));
+        }
+    }
+    low.map(|(_, index)| index)
+}
+
+/// Index of the first largest item of `iter`, or `None` when it is empty.
+#[inline]
+pub fn simple_argmax_iter<'a, T: 'a + Copy + PartialOrd>(
+    iter: impl IntoIterator<Item = &'a T>,
+) -> Option<usize> {
+    let mut high: Option<(T, usize)> = None;
+    for (i, item) in iter.into_iter().enumerate() {
+        if high.is_none_or(|(value, _)| *item > value) {
+            high = Some((*item, i));
+        }
+    }
+    high.map(|(_, index)| index)
+}
+
+/// Index of the first smallest element selected by `mask`, or `None` when
+/// nothing is selected.
+///
+/// # Panics
+///
+/// When `mask` does not cover every element of `arr`.
+pub fn simple_argmin_masked<T: Copy + PartialOrd>(arr: &[T], mask: Mask) -> Option<usize> {
+    simple_min_with_index_masked(arr, mask).map(|(_, index)| index)
+}
+
+/// The smallest element selected by `mask` together with its first index, see
+/// [`simple_argmin_masked`].
+pub fn simple_min_with_index_masked<T: Copy + PartialOrd>(
+    arr: &[T],
+    mask: Mask,
+) -> Option<(T, usize)> {
+    mask.check_len(arr.len());
+    masked_argmin_from(arr, mask, 0)
+}
+
+/// Index of the first largest element selected by `mask`, or `None` when
+/// nothing is selected.
+///
+/// # Panics
+///
+/// When `mask` does not cover every element of `arr`.
+pub fn simple_argmax_masked<T: Copy + PartialOrd>(arr: &[T], mask: Mask) -> Option<usize> {
+    simple_max_with_index_masked(arr, mask).map(|(_, index)| index)
+}
+
+/// The largest element selected by `mask` together with its first index, see
+/// [`simple_argmax_masked`].
+pub fn simple_max_with_index_masked<T: Copy + PartialOrd>(
+    arr: &[T],
+    mask: Mask,
+) -> Option<(T, usize)> {
+    mask.check_len(arr.len());
+    masked_argmax_from(arr, mask, 0)
+}
+
+/// Smallest selected element of `arr` and its index, where `arr` starts at
+/// element `offset` of the masked input.
+#[inline]
+pub(crate) fn masked_argmin_from<T: Copy + PartialOrd>(
+    arr: &[T],
+    mask: Mask,
+    offset: usize,
+) -> Option<(T, usize)> {
+    let mut low: Option<(T, usize)> = None;
+    for (i, item) in arr.iter().enumerate() {
+        if !mask.get(offset + i) {
+            continue;
+        }
+        if low.is_none_or(|(value, _)| *item < value) {
+            low = Some((*item, offset + i));
+        }
+    }
+    low
+}
+
+/// Largest selected element of `arr` and its index, see [`masked_argmin_from`].
+#[inline]
+pub(crate) fn masked_argmax_from<T: Copy + PartialOrd>(
+    arr: &[T],
+    mask: Mask,
+    offset: usize,
+) -> Option<(T, usize)> {
+    let mut high: Option<(T, usize)> = None;
+    for (i, item) in arr.iter().enumerate() {
+        if !mask.get(offset + i) {
+            continue;
+        }
+        if high.is_none_or(|(value, _)| *item > value) {
+            high = Some((*item, offset + i));
+        }
+    }
+    high
+}
+
+/// Position of the smallest of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ...
+/// counted in strided elements.
+#[inline]
+pub fn simple_argmin_strided<T: Copy + PartialOrd>(arr: &[T], stride: usize) -> usize {
+    let mut low_index = 0usize;
+    let mut low = arr[low_index];
+    for (i, item) in arr.iter().step_by(stride).enumerate() {
+        if *item < low {
+            low = *item;
+            low_index = i;
+        }
+    }
+    low_index
+}
+
+/// Position of the largest of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ...
+/// counted in strided elements.
+#[inline]
+pub fn simple_argmax_strided<T: Copy + PartialOrd>(arr: &[T], stride: usize) -> usize {
+    let mut high_index = 0usize;
+    let mut high = arr[high_index];
+    for (i, item) in arr.iter().step_by(stride).enumerate() {
+        if *item > high {
+            high = *item;
+            high_index = i;
+        }
+    }
+    high_index
+}
+
+/// Row of the smallest value in every column of a row-major matrix with `cols`
+/// columns. The first row wins ties.
+///
+/// # Panics
+///
+/// When `cols` is zero or `data` holds less than one row.
+#[inline]
+pub fn simple_argmin_columns<T: Copy + PartialOrd>(data: &[T], cols: usize) -> Vec<usize> {
+    assert!(cols > 0, "matrix must have at least one column");
+    let mut values_low = data[..cols].to_vec();
+    let mut index_low = vec![0usize; cols];
+    for (row_index, row) in data.chunks_exact(cols).enumerate().skip(1) {
+        for (column, item) in row.iter().enumerate() {
+            if *item < values_low[column] {
+                values_low[column] = *item;
+                index_low[column] = row_index;
+            }
+        }
+    }
+    index_low
+}
+
+/// Row of the largest value in every column of a row-major matrix with `cols`
+/// columns. The first row wins ties.
+///
+/// # Panics
+///
+/// When `cols` is zero or `data` holds less than one row.
+#[inline]
+pub fn simple_argmax_columns<T: Copy + PartialOrd>(data: &[T], cols: usize) -> Vec<usize> {
+    assert!(cols > 0, "matrix must have at least one column");
+    let mut values_high = data[..cols].to_vec();
+    let mut index_high = vec![0usize; cols];
+    for (row_index, row) in data.chunks_exact(cols).enumerate().skip(1) {
+        for (column, item) in row.iter().enumerate() {
+            if *item > values_high[column] {
+                values_high[column] = *item;
+                index_high[column] = row_index;
+            }
+        }
+    }
+    index_high
+}
+
+/// Indices of the `k` smallest values in ascending order, ties broken by index.
+#[inline]
+pub fn simple_argmin_k<T: Copy + PartialOrd>(arr: &[T], k: usize) -> Vec<usize> {
+    let mut best = KBest::new(k, k.min(arr.len()), false);
+    for (i, item) in arr.iter().enumerate() {
+        best.push(*item, i);
+    }
+    best.into_indices()
+}
+
+/// Indices of the `k` largest values in descending order, ties broken by index.
+#[inline]
+pub fn simple_argmax_k<T: Copy + PartialOrd>(arr: &[T], k: usize) -> Vec<usize> {
+    let mut best = KBest::new(k, k.min(arr.len()), true);
+    for (i, item) in arr.iter().enumerate() {
+        best.push(*item, i);
+    }
+    best.into_indices()
+}
+
+/// The `k` best `(value, index)` pairs pushed so far. The worst of them sits
+/// on top of the heap so it can be compared against and evicted cheaply.
+pub(crate) struct KBest<T> {
+    heap: BinaryHeap<Candidate<T>>,
+    k: usize,
+    largest: bool,
+}
+
+impl<T: Copy + PartialOrd> KBest<T> {
+    /// `capacity` is reserved up front. Callers clamp it to the input length so
+    /// a huge `k` does not reserve memory the input can never fill.
+    pub(crate) fn new(k: usize, capacity: usize, largest: bool) -> Self {
+        KBest {
+            heap: BinaryHeap::with_capacity(capacity),
+            k,
+            largest,
+        }
+    }
+
+    /// Value a new element has to beat to get in, once `k` elements are kept.
+    #[inline]
+    pub(crate) fn threshold(&self) -> Option<T> {
+        match self.heap.peek() {
+            Some(worst) if self.heap.len() == self.k => Some(worst.value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, value: T, index: usize) {
+        let candidate = Candidate {
+            value,
+            index,
+            largest: self.largest,
+        };
+        if self.heap.len() < self.k {
+            self.heap.push(candidate);
+        } else if let Some(mut worst) = self.heap.peek_mut() {
+            if candidate < *worst {
+                *worst = candidate;
+            }
+        }
+    }
+
+    pub(crate) fn into_indices(self) -> Vec<usize> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|candidate| candidate.index)
+            .collect()
+    }
+}
+
+/// Orders candidates from best to worst. A value that cannot be compared with
+/// itself (NaN) ranks below every other value, so it is only kept when nothing
+/// better is left. Equal values are ordered by index.
+struct Candidate<T> {
+    value: T,
+    index: usize,
+    largest: bool,
+}
+
+impl<T: PartialOrd> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let by_value = match (is_nan(&self.value), is_nan(&other.value)) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                let by_value = self
+                    .value
+                    .partial_cmp(&other.value)
+                    .unwrap_or(Ordering::Equal);
+                if self.largest {
+                    by_value.reverse()
+                } else {
+                    by_value
+                }
+            }
+        };
+        by_value.then(self.index.cmp(&other.index))
+    }
+}
+
+#[inline]
+fn is_nan<T: PartialOrd>(value: &T) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+impl<T: PartialOrd> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Candidate<T> {}
@@ -1,31 +1,260 @@
+mod accumulator;
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "arrow")]
+mod arrow;
+pub mod axis;
+mod dispatch;
 pub mod generic;
-#[cfg(target_feature = "sse")]
+mod mask;
+#[cfg(target_arch = "aarch64")]
+mod neon;
+#[cfg(feature = "rayon")]
+mod par;
+// Always built with the feature so the kernels are also tested on x86_64 and aarch64 hosts.
+#[cfg(feature = "portable")]
+#[cfg_attr(any(target_arch = "x86_64", target_arch = "aarch64"), allow(dead_code))]
+mod portable;
+mod rolling;
+#[cfg(target_arch = "x86_64")]
 mod simd;
-#[cfg(target_feature = "sse")]
+// Also holds the merge helpers used by the accumulator and the rayon chunks.
+#[cfg_attr(
+    not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")),
+    allow(dead_code)
+)]
 mod task;
 
-#[cfg(not(target_feature = "sse"))]
-pub use generic::{simple_argmax, simple_argmin};
-#[cfg(target_feature = "sse")]
-pub use simd::{simd_f32, simd_i16, simd_i32, simd_u16, simd_u8};
+pub use accumulator::ArgMinMaxAccumulator;
+#[cfg(feature = "ndarray")]
+pub use array::ArgMinMaxAxis;
+pub use dispatch::{simd_level, SimdLevel};
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))]
+pub use generic::{
+    simple_argmax, simple_argmax_k, simple_argmax_last, simple_argmax_strided, simple_argmin,
+    simple_argmin_k, simple_argmin_last, simple_argmin_strided, simple_argminmax, simple_first_nan,
+    simple_nanargmax, simple_nanargmin,
+};
+pub use mask::Mask;
+#[cfg(target_arch = "aarch64")]
+pub use neon::{
+    simd_f32, simd_f64, simd_i16, simd_i32, simd_i64, simd_i8, simd_u16, simd_u32, simd_u64,
+    simd_u8,
+};
+#[cfg(feature = "rayon")]
+pub use par::ParArgMinMax;
+#[cfg(all(
+    feature = "portable",
+    not(any(target_arch = "x86_64", target_arch = "aarch64"))
+))]
+pub use portable::{
+    simd_f32, simd_f64, simd_i16, simd_i32, simd_i64, simd_i8, simd_u16, simd_u32, simd_u64,
+    simd_u8,
+};
+pub use rolling::{rolling_argmax, rolling_argmin};
+#[cfg(target_arch = "x86_64")]
+pub use simd::{
+    simd_f32, simd_f64, simd_i16, simd_i32, simd_i64, simd_i8, simd_u16, simd_u32, simd_u64,
+    simd_u8,
+};
 
 pub trait ArgMinMax {
+    /// Element type, returned next to the index by the `*_with_index` methods.
+    type Value: Copy;
+
     fn argmin(&self) -> Option<usize>;
     fn argmax(&self) -> Option<usize>;
+
+    /// Both indices at once. The implementations in this crate find them in a
+    /// single pass; the default falls back to [`argmin`](Self::argmin) and
+    /// [`argmax`](Self::argmax).
+    fn argminmax(&self) -> Option<(usize, usize)> {
+        Some((self.argmin()?, self.argmax()?))
+    }
+
+    /// Smallest value and its index, saving the caller the lookup.
+    fn min_with_index(&self) -> Option<(Self::Value, usize)>;
+    /// Largest value and its index, saving the caller the lookup.
+    fn max_with_index(&self) -> Option<(Self::Value, usize)>;
+    /// `(min, index)` and `(max, index)` found in a single pass.
+    #[allow(clippy::type_complexity)]
+    fn minmax_with_indices(&self) -> Option<((Self::Value, usize), (Self::Value, usize))>;
+}
+
+/// How NaN values are treated by [`NanArgMinMax`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// Skip NaN values, like NumPy's `nanargmin`/`nanargmax`.
+    Ignore,
+    /// A NaN wins over every other value, so the index of the first NaN is returned.
+    Propagate,
+}
+
+pub trait NanArgMinMax {
+    fn argmin_with(&self, policy: NanPolicy) -> Option<usize>;
+    fn argmax_with(&self, policy: NanPolicy) -> Option<usize>;
+
+    fn nanargmin(&self) -> Option<usize> {
+        self.argmin_with(NanPolicy::Ignore)
+    }
+
+    fn nanargmax(&self) -> Option<usize> {
+        self.argmax_with(NanPolicy::Ignore)
+    }
+}
+
+/// Which index [`TieBreakArgMinMax`] returns when several elements share the
+/// extreme value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The first index, as returned by [`ArgMinMax`].
+    First,
+    /// The last index, for example the most recent peak of a time series.
+    Last,
+}
+
+pub trait TieBreakArgMinMax {
+    fn argmin_tie_break(&self, tie: TieBreak) -> Option<usize>;
+    fn argmax_tie_break(&self, tie: TieBreak) -> Option<usize>;
+
+    fn argmin_last(&self) -> Option<usize> {
+        self.argmin_tie_break(TieBreak::Last)
+    }
+
+    fn argmax_last(&self) -> Option<usize> {
+        self.argmax_tie_break(TieBreak::Last)
+    }
+}
+
+/// Indices of the `k` smallest or largest values. Ties are broken by index, and
+/// fewer than `k` indices are returned when the input is shorter than `k`.
+pub trait TopK {
+    /// Indices of the `k` smallest values, ordered from smallest to largest.
+    fn argmin_k(&self, k: usize) -> Vec<usize>;
+    /// Indices of the `k` largest values, ordered from largest to smallest.
+    fn argmax_k(&self, k: usize) -> Vec<usize>;
+}
+
+/// Argmin/argmax over `self[0]`, `self[stride]`, `self[2 * stride]`, ..., for
+/// example one channel of interleaved data. The returned index counts strided
+/// elements, so `Some(k)` refers to `self[k * stride]`. Start from a subslice
+/// such as `&data[1..]` to pick another channel.
+///
+/// # Panics
+///
+/// When `stride` is zero.
+pub trait StridedArgMinMax {
+    fn argmin_strided(&self, stride: usize) -> Option<usize>;
+    fn argmax_strided(&self, stride: usize) -> Option<usize>;
+}
+
+/// Argmin/argmax over the elements selected by a [`Mask`], such as "the largest
+/// value among the selected rows". Returns `None` when nothing is selected.
+///
+/// Only `f32`, `i32` and `u32` have SIMD kernels, which fold the mask into the
+/// SSE4.1/AVX2 compares on x86_64. The other types, and other targets, scan the
+/// mask with a scalar loop.
+///
+/// # Panics
+///
+/// When the mask does not cover every element.
+pub trait MaskedArgMinMax: ArgMinMax {
+    /// Smallest selected value and its index.
+    fn min_with_index_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<(Self::Value, usize)>;
+    /// Largest selected value and its index.
+    fn max_with_index_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<(Self::Value, usize)>;
+
+    fn argmin_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<usize> {
+        self.min_with_index_masked(mask).map(|(_, index)| index)
+    }
+
+    fn argmax_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<usize> {
+        self.max_with_index_masked(mask).map(|(_, index)| index)
+    }
+}
+
+/// The value-returning methods of [`ArgMinMax`] for slices and vectors, taking
+/// the value from the kernel that found the index.
+macro_rules! with_index_methods {
+    ($t:ty, $min:path, $max:path, $minmax:path) => {
+        type Value = $t;
+
+        fn min_with_index(&self) -> Option<($t, usize)> {
+            #[cfg(not(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                feature = "portable"
+            )))]
+            return (!self.is_empty()).then(|| task::with_value(self, simple_argmin(self)));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))]
+            return $min(self);
+        }
+
+        fn max_with_index(&self) -> Option<($t, usize)> {
+            #[cfg(not(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                feature = "portable"
+            )))]
+            return (!self.is_empty()).then(|| task::with_value(self, simple_argmax(self)));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))]
+            return $max(self);
+        }
+
+        fn minmax_with_indices(&self) -> Option<(($t, usize), ($t, usize))> {
+            #[cfg(not(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                feature = "portable"
+            )))]
+            return (!self.is_empty()).then(|| task::with_values(self, simple_argminmax(self)));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))]
+            return $minmax(self);
+        }
+    };
 }
 
 macro_rules! impl_argmm_f32 {
     ($($b:ty),*) => {
         $(impl ArgMinMax for $b {
+            with_index_methods!(f32, simd_f32::min_with_index_f32, simd_f32::max_with_index_f32, simd_f32::minmax_with_indices_f32);
 
             fn argmin(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmin(self));
-            #[cfg(target_feature = "sse")] return simd_f32::argmin_f32(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_f32::argmin_f32(self);
             }
 
             fn argmax(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmax(self));
-            #[cfg(target_feature = "sse")] return simd_f32::argmax_f32(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_f32::argmax_f32(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_f32::argminmax_f32(self);
+            }
+        })*
+    }
+}
+
+macro_rules! impl_argmm_f64 {
+    ($($b:ty),*) => {
+        $(impl ArgMinMax for $b {
+            with_index_methods!(f64, simd_f64::min_with_index_f64, simd_f64::max_with_index_f64, simd_f64::minmax_with_indices_f64);
+
+            fn argmin(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_f64::argmin_f64(self);
+            }
+
+            fn argmax(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_f64::argmax_f64(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_f64::argminmax_f64(self);
             }
         })*
     }
@@ -34,15 +263,44 @@ macro_rules! impl_argmm_f32 {
 macro_rules! impl_argmm_i32 {
     ($($b:ty),*) => {
         $(impl ArgMinMax for $b {
+            with_index_methods!(i32, simd_i32::min_with_index_i32, simd_i32::max_with_index_i32, simd_i32::minmax_with_indices_i32);
 
             fn argmin(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmin(self));
-            #[cfg(target_feature = "sse")] return simd_i32::argmin_i32(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i32::argmin_i32(self);
             }
 
             fn argmax(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmax(self));
-            #[cfg(target_feature = "sse")] return simd_i32::argmax_i32(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i32::argmax_i32(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i32::argminmax_i32(self);
+            }
+        })*
+    }
+}
+
+macro_rules! impl_argmm_i64 {
+    ($($b:ty),*) => {
+        $(impl ArgMinMax for $b {
+            with_index_methods!(i64, simd_i64::min_with_index_i64, simd_i64::max_with_index_i64, simd_i64::minmax_with_indices_i64);
+
+            fn argmin(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i64::argmin_i64(self);
+            }
+
+            fn argmax(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i64::argmax_i64(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i64::argminmax_i64(self);
             }
         })*
     }
@@ -51,15 +309,21 @@ macro_rules! impl_argmm_i32 {
 macro_rules! impl_argmm_i16 {
     ($($b:ty),*) => {
         $(impl ArgMinMax for $b {
+            with_index_methods!(i16, simd_i16::min_with_index_i16, simd_i16::max_with_index_i16, simd_i16::minmax_with_indices_i16);
 
             fn argmin(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmin(self));
-            #[cfg(target_feature = "sse")] return simd_i16::argmin_i16(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i16::argmin_i16(self);
             }
 
             fn argmax(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmax(self));
-            #[cfg(target_feature = "sse")] return simd_i16::argmax_i16(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i16::argmax_i16(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i16::argminmax_i16(self);
             }
         })*
     }
@@ -68,15 +332,67 @@ macro_rules! impl_argmm_i16 {
 macro_rules! impl_argmm_u16 {
     ($($b:ty),*) => {
         $(impl ArgMinMax for $b {
+            with_index_methods!(u16, simd_u16::min_with_index_u16, simd_u16::max_with_index_u16, simd_u16::minmax_with_indices_u16);
 
             fn argmin(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmin(self));
-            #[cfg(target_feature = "sse")] return simd_u16::argmin_u16(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u16::argmin_u16(self);
             }
 
             fn argmax(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmax(self));
-            #[cfg(target_feature = "sse")] return simd_u16::argmax_u16(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u16::argmax_u16(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u16::argminmax_u16(self);
+            }
+        })*
+    }
+}
+
+macro_rules! impl_argmm_u32 {
+    ($($b:ty),*) => {
+        $(impl ArgMinMax for $b {
+            with_index_methods!(u32, simd_u32::min_with_index_u32, simd_u32::max_with_index_u32, simd_u32::minmax_with_indices_u32);
+
+            fn argmin(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u32::argmin_u32(self);
+            }
+
+            fn argmax(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u32::argmax_u32(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u32::argminmax_u32(self);
+            }
+        })*
+    }
+}
+
+macro_rules! impl_argmm_u64 {
+    ($($b:ty),*) => {
+        $(impl ArgMinMax for $b {
+            with_index_methods!(u64, simd_u64::min_with_index_u64, simd_u64::max_with_index_u64, simd_u64::minmax_with_indices_u64);
+
+            fn argmin(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u64::argmin_u64(self);
+            }
+
+            fn argmax(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u64::argmax_u64(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u64::argminmax_u64(self);
             }
         })*
     }
@@ -85,22 +401,540 @@ macro_rules! impl_argmm_u16 {
 macro_rules! impl_argmm_u8 {
     ($($b:ty),*) => {
         $(impl ArgMinMax for $b {
+            with_index_methods!(u8, simd_u8::min_with_index_u8, simd_u8::max_with_index_u8, simd_u8::minmax_with_indices_u8);
 
             fn argmin(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmin(self));
-            #[cfg(target_feature = "sse")] return simd_u8::argmin_u8(self) ;
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u8::argmin_u8(self) ;
             }
 
             fn argmax(&self) -> Option<usize> {
-            #[cfg(not(target_feature = "sse"))] return Some(simple_argmax(self));
-            #[cfg(target_feature = "sse")] return simd_u8::argmax_u8(self);
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u8::argmax_u8(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_u8::argminmax_u8(self);
             }
         })*
     }
 }
 
+macro_rules! impl_argmm_i8 {
+    ($($b:ty),*) => {
+        $(impl ArgMinMax for $b {
+            with_index_methods!(i8, simd_i8::min_with_index_i8, simd_i8::max_with_index_i8, simd_i8::minmax_with_indices_i8);
+
+            fn argmin(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i8::argmin_i8(self) ;
+            }
+
+            fn argmax(&self) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i8::argmax_i8(self);
+            }
+
+            fn argminmax(&self) -> Option<(usize, usize)> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argminmax(self));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return simd_i8::argminmax_i8(self);
+            }
+        })*
+    }
+}
+
+macro_rules! impl_nan_argmm_f32 {
+    ($($b:ty),*) => {
+        $(impl NanArgMinMax for $b {
+
+            fn argmin_with(&self, policy: NanPolicy) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return match policy {
+                NanPolicy::Ignore => simple_nanargmin(self),
+                NanPolicy::Propagate => simple_first_nan(self).or_else(|| (!self.is_empty()).then(|| simple_argmin(self))),
+            };
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return match policy {
+                NanPolicy::Ignore => simd_f32::nanargmin_f32(self),
+                NanPolicy::Propagate => simd_f32::first_nan_f32(self).or_else(|| self.argmin()),
+            };
+            }
+
+            fn argmax_with(&self, policy: NanPolicy) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return match policy {
+                NanPolicy::Ignore => simple_nanargmax(self),
+                NanPolicy::Propagate => simple_first_nan(self).or_else(|| (!self.is_empty()).then(|| simple_argmax(self))),
+            };
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return match policy {
+                NanPolicy::Ignore => simd_f32::nanargmax_f32(self),
+                NanPolicy::Propagate => simd_f32::first_nan_f32(self).or_else(|| self.argmax()),
+            };
+            }
+        })*
+    }
+}
+
+macro_rules! impl_top_k {
+    ($argmin:path, $argmax:path; $($b:ty),*) => {
+        $(impl TopK for $b {
+
+            fn argmin_k(&self, k: usize) -> Vec<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return simple_argmin_k(self, k);
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return task::prefiltered_top_k(self, k, false, $argmin);
+            }
+
+            fn argmax_k(&self, k: usize) -> Vec<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return simple_argmax_k(self, k);
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return task::prefiltered_top_k(self, k, true, $argmax);
+            }
+        })*
+    }
+}
+
+macro_rules! impl_strided {
+    ($argmin:path, $argmax:path; $($b:ty),*) => {
+        $(impl StridedArgMinMax for $b {
+
+            fn argmin_strided(&self, stride: usize) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin_strided(self, stride));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return task::deinterleaved_argmin(self, stride, $argmin);
+            }
+
+            fn argmax_strided(&self, stride: usize) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax_strided(self, stride));
+            #[cfg(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable"))] return task::deinterleaved_argmax(self, stride, $argmax);
+            }
+        })*
+    };
+    (gather $argmin_strided:path, $argmax_strided:path, $argmin:path, $argmax:path; $($b:ty),*) => {
+        $(impl StridedArgMinMax for $b {
+
+            fn argmin_strided(&self, stride: usize) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin_strided(self, stride));
+            #[cfg(target_arch = "x86_64")] return $argmin_strided(self, stride);
+            #[cfg(all(not(target_arch = "x86_64"), any(target_arch = "aarch64", feature = "portable")))] return task::deinterleaved_argmin(self, stride, $argmin);
+            }
+
+            fn argmax_strided(&self, stride: usize) -> Option<usize> {
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax_strided(self, stride));
+            #[cfg(target_arch = "x86_64")] return $argmax_strided(self, stride);
+            #[cfg(all(not(target_arch = "x86_64"), any(target_arch = "aarch64", feature = "portable")))] return task::deinterleaved_argmax(self, stride, $argmax);
+            }
+        })*
+    };
+}
+
+macro_rules! impl_masked {
+    // Types without masked SIMD kernels.
+    ($t:ty; $($b:ty),*) => {
+        $(impl MaskedArgMinMax for $b {
+
+            fn min_with_index_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<($t, usize)> {
+                generic::simple_min_with_index_masked(self, mask.into())
+            }
+
+            fn max_with_index_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<($t, usize)> {
+                generic::simple_max_with_index_masked(self, mask.into())
+            }
+        })*
+    };
+    (simd $t:ty, $min_masked:path, $max_masked:path; $($b:ty),*) => {
+        $(impl MaskedArgMinMax for $b {
+
+            fn min_with_index_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<($t, usize)> {
+            #[cfg(not(target_arch = "x86_64"))] return generic::simple_min_with_index_masked(self, mask.into());
+            #[cfg(target_arch = "x86_64")] return $min_masked(self, mask.into());
+            }
+
+            fn max_with_index_masked<'a>(&self, mask: impl Into<Mask<'a>>) -> Option<($t, usize)> {
+            #[cfg(not(target_arch = "x86_64"))] return generic::simple_max_with_index_masked(self, mask.into());
+            #[cfg(target_arch = "x86_64")] return $max_masked(self, mask.into());
+            }
+        })*
+    };
+}
+
+macro_rules! impl_tie_break {
+    (native $argmin_last:path, $argmax_last:path, $argmin:path, $argmax:path; $($b:ty),*) => {
+        $(impl TieBreakArgMinMax for $b {
+
+            fn argmin_tie_break(&self, tie: TieBreak) -> Option<usize> {
+                if tie == TieBreak::First {
+                    return self.argmin();
+                }
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmin_last(self));
+            #[cfg(target_arch = "x86_64")] return $argmin_last(self);
+            #[cfg(all(not(target_arch = "x86_64"), any(target_arch = "aarch64", feature = "portable")))] return $argmin(self).map(|first| task::last_occurrence(self, first));
+            }
+
+            fn argmax_tie_break(&self, tie: TieBreak) -> Option<usize> {
+                if tie == TieBreak::First {
+                    return self.argmax();
+                }
+            #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64", feature = "portable")))] return (!self.is_empty()).then(|| simple_argmax_last(self));
+            #[cfg(target_arch = "x86_64")] return $argmax_last(self);
+            #[cfg(all(not(target_arch = "x86_64"), any(target_arch = "aarch64", feature = "portable")))] return $argmax(self).map(|first| task::last_occurrence(self, first));
+            }
+        })*
+    };
+}
+
 impl_argmm_f32!(Vec<f32>, &[f32], [f32]);
+impl_argmm_f64!(Vec<f64>, &[f64], [f64]);
+impl_nan_argmm_f32!(Vec<f32>, &[f32], [f32]);
 impl_argmm_i32!(Vec<i32>, &[i32], [i32]);
+impl_argmm_i64!(Vec<i64>, &[i64], [i64]);
 impl_argmm_i16!(Vec<i16>, &[i16], [i16]);
 impl_argmm_u16!(Vec<u16>, &[u16], [u16]);
+impl_argmm_u32!(Vec<u32>, &[u32], [u32]);
+impl_argmm_u64!(Vec<u64>, &[u64], [u64]);
 impl_argmm_u8!(Vec<u8>, &[u8], [u8]);
+impl_argmm_i8!(Vec<i8>, &[i8], [i8]);
+
+impl_strided!(gather simd_f32::argmin_f32_strided, simd_f32::argmax_f32_strided, simd_f32::argmin_f32, simd_f32::argmax_f32; Vec<f32>, &[f32], [f32]);
+impl_strided!(simd_f64::argmin_f64, simd_f64::argmax_f64; Vec<f64>, &[f64], [f64]);
+impl_strided!(gather simd_i32::argmin_i32_strided, simd_i32::argmax_i32_strided, simd_i32::argmin_i32, simd_i32::argmax_i32; Vec<i32>, &[i32], [i32]);
+impl_strided!(simd_i64::argmin_i64, simd_i64::argmax_i64; Vec<i64>, &[i64], [i64]);
+impl_strided!(simd_i16::argmin_i16, simd_i16::argmax_i16; Vec<i16>, &[i16], [i16]);
+impl_strided!(simd_u16::argmin_u16, simd_u16::argmax_u16; Vec<u16>, &[u16], [u16]);
+impl_strided!(gather simd_u32::argmin_u32_strided, simd_u32::argmax_u32_strided, simd_u32::argmin_u32, simd_u32::argmax_u32; Vec<u32>, &[u32], [u32]);
+impl_strided!(simd_u64::argmin_u64, simd_u64::argmax_u64; Vec<u64>, &[u64], [u64]);
+impl_strided!(simd_u8::argmin_u8, simd_u8::argmax_u8; Vec<u8>, &[u8], [u8]);
+impl_strided!(simd_i8::argmin_i8, simd_i8::argmax_i8; Vec<i8>, &[i8], [i8]);
+
+impl_top_k!(simd_f32::argmin_f32, simd_f32::argmax_f32; Vec<f32>, &[f32], [f32]);
+impl_top_k!(simd_f64::argmin_f64, simd_f64::argmax_f64; Vec<f64>, &[f64], [f64]);
+impl_top_k!(simd_i32::argmin_i32, simd_i32::argmax_i32; Vec<i32>, &[i32], [i32]);
+impl_top_k!(simd_i64::argmin_i64, simd_i64::argmax_i64; Vec<i64>, &[i64], [i64]);
+impl_top_k!(simd_i16::argmin_i16, simd_i16::argmax_i16; Vec<i16>, &[i16], [i16]);
+impl_top_k!(simd_u16::argmin_u16, simd_u16::argmax_u16; Vec<u16>, &[u16], [u16]);
+impl_top_k!(simd_u32::argmin_u32, simd_u32::argmax_u32; Vec<u32>, &[u32], [u32]);
+impl_top_k!(simd_u64::argmin_u64, simd_u64::argmax_u64; Vec<u64>, &[u64], [u64]);
+impl_top_k!(simd_u8::argmin_u8, simd_u8::argmax_u8; Vec<u8>, &[u8], [u8]);
+impl_top_k!(simd_i8::argmin_i8, simd_i8::argmax_i8; Vec<i8>, &[i8], [i8]);
+
+impl_tie_break!(native simd_f32::argmin_f32_last, simd_f32::argmax_f32_last, simd_f32::argmin_f32, simd_f32::argmax_f32; Vec<f32>, &[f32], [f32]);
+impl_tie_break!(native simd_f64::argmin_f64_last, simd_f64::argmax_f64_last, simd_f64::argmin_f64, simd_f64::argmax_f64; Vec<f64>, &[f64], [f64]);
+impl_tie_break!(native simd_i32::argmin_i32_last, simd_i32::argmax_i32_last, simd_i32::argmin_i32, simd_i32::argmax_i32; Vec<i32>, &[i32], [i32]);
+impl_tie_break!(native simd_i64::argmin_i64_last, simd_i64::argmax_i64_last, simd_i64::argmin_i64, simd_i64::argmax_i64; Vec<i64>, &[i64], [i64]);
+impl_tie_break!(native simd_i16::argmin_i16_last, simd_i16::argmax_i16_last, simd_i16::argmin_i16, simd_i16::argmax_i16; Vec<i16>, &[i16], [i16]);
+impl_tie_break!(native simd_u16::argmin_u16_last, simd_u16::argmax_u16_last, simd_u16::argmin_u16, simd_u16::argmax_u16; Vec<u16>, &[u16], [u16]);
+impl_tie_break!(native simd_u32::argmin_u32_last, simd_u32::argmax_u32_last, simd_u32::argmin_u32, simd_u32::argmax_u32; Vec<u32>, &[u32], [u32]);
+impl_tie_break!(native simd_u64::argmin_u64_last, simd_u64::argmax_u64_last, simd_u64::argmin_u64, simd_u64::argmax_u64; Vec<u64>, &[u64], [u64]);
+impl_tie_break!(native simd_u8::argmin_u8_last, simd_u8::argmax_u8_last, simd_u8::argmin_u8, simd_u8::argmax_u8; Vec<u8>, &[u8], [u8]);
+impl_tie_break!(native simd_i8::argmin_i8_last, simd_i8::argmax_i8_last, simd_i8::argmin_i8, simd_i8::argmax_i8; Vec<i8>, &[i8], [i8]);
+
+impl_masked!(simd f32, simd_f32::min_with_index_f32_masked, simd_f32::max_with_index_f32_masked; Vec<f32>, &[f32], [f32]);
+impl_masked!(f64; Vec<f64>, &[f64], [f64]);
+impl_masked!(simd i32, simd_i32::min_with_index_i32_masked, simd_i32::max_with_index_i32_masked; Vec<i32>, &[i32], [i32]);
+impl_masked!(i64; Vec<i64>, &[i64], [i64]);
+impl_masked!(i16; Vec<i16>, &[i16], [i16]);
+impl_masked!(u16; Vec<u16>, &[u16], [u16]);
+impl_masked!(simd u32, simd_u32::min_with_index_u32_masked, simd_u32::max_with_index_u32_masked; Vec<u32>, &[u32], [u32]);
+impl_masked!(u64; Vec<u64>, &[u64], [u64]);
+impl_masked!(u8; Vec<u8>, &[u8], [u8]);
+impl_masked!(i8; Vec<i8>, &[i8], [i8]);
+
+#[cfg(test)]
+mod tests {
+    use super::generic::{
+        simple_argmax_k, simple_argmax_strided, simple_argmin_k, simple_argmin_strided,
+    };
+    use super::{
+        ArgMinMax, Mask, MaskedArgMinMax, NanArgMinMax, NanPolicy, StridedArgMinMax, TieBreak,
+        TieBreakArgMinMax, TopK,
+    };
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    #[test]
+    fn test_nan_policy_selects_first_nan_or_skips_it() {
+        let mut data: Vec<f32> = (0..37).map(|i| (i as f32 - 18.0).abs()).collect();
+        data[3] = f32::NAN;
+        data[33] = f32::NAN;
+
+        assert_eq!(data.argmin_with(NanPolicy::Propagate), Some(3));
+        assert_eq!(data.argmax_with(NanPolicy::Propagate), Some(3));
+        assert_eq!(data.nanargmin(), Some(18));
+        assert_eq!(data.nanargmax(), Some(0));
+    }
+
+    #[test]
+    fn test_nan_policy_without_nan_matches_argmin_and_argmax() {
+        let data: Vec<f32> = (0..37).map(|i| ((i * 7) % 37) as f32).collect();
+
+        for policy in [NanPolicy::Ignore, NanPolicy::Propagate] {
+            assert_eq!(data.argmin_with(policy), data.argmin());
+            assert_eq!(data.argmax_with(policy), data.argmax());
+        }
+    }
+
+    #[test]
+    fn test_empty_input_returns_none() {
+        let data: Vec<f32> = Vec::new();
+        assert_eq!(data.argmin(), None);
+        assert_eq!(data.argmax(), None);
+        assert_eq!(data.argminmax(), None);
+        assert_eq!(data.argmin_with(NanPolicy::Propagate), None);
+        assert_eq!(data.nanargmax(), None);
+    }
+
+    #[test]
+    fn test_with_index_returns_value_at_index_for_every_type() {
+        macro_rules! check {
+            ($($t:ty),*) => {$(
+                for n in [1, 7, 33, 1000] {
+                    let data: Vec<$t> = (0..n).map(|i| ((i * 7_919) % 97) as $t).collect();
+                    let (min, max) = data.argminmax().unwrap();
+                    assert_eq!(data.min_with_index(), Some((data[min], min)));
+                    assert_eq!(data.max_with_index(), Some((data[max], max)));
+                    assert_eq!(
+                        data.as_slice().minmax_with_indices(),
+                        Some(((data[min], min), (data[max], max)))
+                    );
+                }
+                assert_eq!(Vec::<$t>::new().min_with_index(), None);
+            )*};
+        }
+        check!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+    }
+
+    fn sorted_indices<T: Copy + PartialOrd>(data: &[T], largest: bool) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..data.len()).collect();
+        indices.sort_by(|&a, &b| {
+            let by_value = data[a].partial_cmp(&data[b]).unwrap();
+            let by_value = if largest {
+                by_value.reverse()
+            } else {
+                by_value
+            };
+            by_value.then(a.cmp(&b))
+        });
+        indices
+    }
+
+    #[test]
+    fn test_top_k_matches_sorting() {
+        for n in [1, 9, 1023, 5000] {
+            let data: Vec<u8> = thread_rng()
+                .sample_iter(Uniform::new_inclusive(0, 255))
+                .take(n)
+                .collect();
+            let data_f32: Vec<f32> = thread_rng()
+                .sample_iter(Uniform::new(-1.0, 1.0))
+                .take(n)
+                .collect();
+            let data_i64: Vec<i64> = thread_rng()
+                .sample_iter(Uniform::new(-50, 50))
+                .take(n)
+                .collect();
+
+            for k in [0, 1, 5, 10, n, n + 3] {
+                let top = k.min(n);
+                assert_eq!(data.argmin_k(k), sorted_indices(&data, false)[..top]);
+                assert_eq!(data.argmax_k(k), sorted_indices(&data, true)[..top]);
+                assert_eq!(
+                    data_f32.argmin_k(k),
+                    sorted_indices(&data_f32, false)[..top]
+                );
+                assert_eq!(data_f32.argmax_k(k), sorted_indices(&data_f32, true)[..top]);
+                assert_eq!(
+                    data_i64.argmin_k(k),
+                    sorted_indices(&data_i64, false)[..top]
+                );
+                assert_eq!(data_i64.argmax_k(k), sorted_indices(&data_i64, true)[..top]);
+                assert_eq!(simple_argmin_k(&data, k), data.argmin_k(k));
+                assert_eq!(simple_argmax_k(&data, k), data.argmax_k(k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_top_k_breaks_ties_by_index() {
+        let mut data = vec![5u16; 3000];
+        data[2500] = 1;
+        data[40] = 9;
+        data[2999] = 9;
+
+        assert_eq!(data.argmin_k(4), vec![2500, 0, 1, 2]);
+        assert_eq!(data.argmax_k(4), vec![40, 2999, 0, 1]);
+        assert_eq!(data.as_slice().argmax_k(1), vec![40]);
+    }
+
+    #[test]
+    fn test_top_k_ranks_nan_worst() {
+        let mut data: Vec<f32> = (0..3000).map(|i| ((i * 7) % 3000) as f32).collect();
+        for i in [0, 1, 2, 1500, 2999] {
+            data[i] = f32::NAN;
+        }
+        let numbers = data.len() - 5;
+
+        for k in [1, 3, 10, numbers] {
+            let smallest = data.argmin_k(k);
+            let largest = data.argmax_k(k);
+            assert_eq!(smallest, simple_argmin_k(&data, k));
+            assert_eq!(largest, simple_argmax_k(&data, k));
+            assert!(smallest.iter().all(|&i| !data[i].is_nan()));
+            assert!(largest.iter().all(|&i| !data[i].is_nan()));
+        }
+        assert_eq!(data.argmin_k(3), vec![2143, 1286, 429]);
+        assert_eq!(data.argmax_k(1), vec![857]);
+
+        // NaNs fill the tail once the numbers run out, in index order.
+        assert_eq!(data.argmin_k(numbers + 2)[numbers..], [0, 1]);
+        assert_eq!(data.argmax_k(data.len())[numbers..], [0, 1, 2, 1500, 2999]);
+    }
+
+    #[test]
+    fn test_top_k_with_huge_k_returns_every_index() {
+        let data: Vec<f32> = (0..2_000).map(|i| ((i * 7) % 2_000) as f32).collect();
+        let ascending = sorted_indices(&data, false);
+        assert_eq!(data.argmin_k(usize::MAX), ascending);
+        assert_eq!(data.argmax_k(1 << 40).len(), data.len());
+        assert_eq!(
+            super::generic::simple_argmin_k(&data, usize::MAX),
+            ascending
+        );
+    }
+
+    #[test]
+    fn test_strided_matches_scalar_on_every_channel() {
+        for n in [1, 5, 100, 5003, 20_000] {
+            let data: Vec<u8> = thread_rng()
+                .sample_iter(Uniform::new_inclusive(0, 255))
+                .take(n)
+                .collect();
+            let data_f32: Vec<f32> = thread_rng()
+                .sample_iter(Uniform::new(-1.0, 1.0))
+                .take(n)
+                .collect();
+            let data_u32: Vec<u32> = thread_rng()
+                .sample_iter(Uniform::new(0, 1000))
+                .take(n)
+                .collect();
+            let data_i64: Vec<i64> = thread_rng()
+                .sample_iter(Uniform::new(-1000, 1000))
+                .take(n)
+                .collect();
+
+            for stride in [1, 2, 3, 4, 5, 8, 9] {
+                for channel in 0..stride.min(n) {
+                    let data = &data[channel..];
+                    let data_f32 = &data_f32[channel..];
+                    let data_u32 = &data_u32[channel..];
+                    let data_i64 = &data_i64[channel..];
+
+                    assert_eq!(
+                        data.argmin_strided(stride),
+                        Some(simple_argmin_strided(data, stride))
+                    );
+                    assert_eq!(
+                        data.argmax_strided(stride),
+                        Some(simple_argmax_strided(data, stride))
+                    );
+                    assert_eq!(
+                        data_f32.argmin_strided(stride),
+                        Some(simple_argmin_strided(data_f32, stride))
+                    );
+                    assert_eq!(
+                        data_f32.argmax_strided(stride),
+                        Some(simple_argmax_strided(data_f32, stride))
+                    );
+                    assert_eq!(
+                        data_u32.argmin_strided(stride),
+                        Some(simple_argmin_strided(data_u32, stride))
+                    );
+                    assert_eq!(
+                        data_u32.argmax_strided(stride),
+                        Some(simple_argmax_strided(data_u32, stride))
+                    );
+                    assert_eq!(
+                        data_i64.argmin_strided(stride),
+                        Some(simple_argmin_strided(data_i64, stride))
+                    );
+                    assert_eq!(
+                        data_i64.argmax_strided(stride),
+                        Some(simple_argmax_strided(data_i64, stride))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strided_ignores_other_channels() {
+        let mut xyz = vec![0.0f32; 3 * 2000];
+        xyz[3 * 1500] = -5.0;
+        xyz[3 * 1700] = -5.0;
+        xyz[3 * 40 + 1] = 9.0;
+        xyz[3 * 10 + 1] = -9.0;
+
+        assert_eq!(xyz.argmin_strided(3), Some(1500));
+        assert_eq!(xyz.argmax_strided(3), Some(0));
+        assert_eq!(xyz[1..].argmin_strided(3), Some(10));
+        assert_eq!(xyz[1..].argmax_strided(3), Some(40));
+        assert_eq!(Vec::<u8>::new().argmin_strided(2), None);
+    }
+
+    #[test]
+    fn test_masked_skips_unselected_extremes() {
+        let values: Vec<i32> = (0..100).map(|i| (i * 37) % 101).collect();
+        let selected: Vec<bool> = (0..100).map(|i| i % 3 == 0).collect();
+        let bits = [0x9249_2492_4924_9249u64, 0x0000_0009_2492_4924];
+
+        let expected_min = (0..100).step_by(3).min_by_key(|&i| values[i]);
+        let expected_max = (0..100).step_by(3).max_by_key(|&i| values[i]);
+        assert_eq!(values.argmin_masked(&selected), expected_min);
+        assert_eq!(values.argmax_masked(&selected), expected_max);
+        assert_eq!(values.argmin_masked(&bits[..]), expected_min);
+        assert_eq!(values.argmax_masked(&bits[..]), expected_max);
+        assert_eq!(
+            values.min_with_index_masked(&selected),
+            expected_min.map(|i| (values[i], i))
+        );
+
+        let values: Vec<u8> = values.iter().map(|&x| x as u8).collect();
+        assert_eq!(values.argmin_masked(Mask::Bits(&bits)), expected_min);
+        assert_eq!(values.argmax_masked(Mask::Bools(&selected)), expected_max);
+        assert_eq!(
+            values.max_with_index_masked(&bits[..]),
+            expected_max.map(|i| (values[i], i))
+        );
+    }
+
+    #[test]
+    fn test_masked_returns_none_when_nothing_selected() {
+        let values = vec![1.0f32; 50];
+        assert_eq!(values.argmin_masked(&vec![false; 50]), None);
+        assert_eq!(values.argmax_masked(Mask::Bits(&[0])), None);
+        assert_eq!(Vec::<f64>::new().argmin_masked(Mask::Bits(&[])), None);
+        assert_eq!(values.argmax_masked(&[false, true].repeat(25)), Some(1));
+    }
+
+    #[test]
+    fn test_last_tie_break_matches_scalar_for_every_type() {
+        use super::generic::{simple_argmax_last, simple_argmin_last};
+
+        macro_rules! check {
+            ($($t:ty),*) => {$(
+                for n in [1, 7, 33, 1000, 70_001] {
+                    // Few distinct values, so every extremum is repeated.
+                    let data: Vec<$t> = (0..n).map(|i| ((i * 7_919) % 13) as $t).collect();
+                    assert_eq!(data.argmin_last(), Some(simple_argmin_last(&data)));
+                    assert_eq!(data.argmax_last(), Some(simple_argmax_last(&data)));
+                    assert_eq!(data.argmin_tie_break(TieBreak::First), data.argmin());
+                    assert_eq!(data.argmax_tie_break(TieBreak::First), data.argmax());
+                }
+            )*};
+        }
+        check!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+    }
+
+    #[test]
+    fn test_last_tie_break_picks_most_recent_peak() {
+        let series = vec![1u16, 5, 3, 5, 2, 5, 0, 4, 0];
+        assert_eq!(series.argmax(), Some(1));
+        assert_eq!(series.argmax_last(), Some(5));
+        assert_eq!(series.argmin_last(), Some(8));
+        assert_eq!(Vec::<f64>::new().argmax_last(), None);
+    }
+}
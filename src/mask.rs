@@ -0,0 +1,138 @@
+#[cfg(target_arch = "x86_64")]
+use std::convert::TryInto;
+
+/// Selects the elements considered by [`MaskedArgMinMax`](crate::MaskedArgMinMax).
+///
+/// `Bits` is a packed bitmap in the Arrow layout: element `i` is selected when
+/// bit `i % 64` of word `i / 64` is set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mask<'a> {
+    Bools(&'a [bool]),
+    Bits(&'a [u64]),
+}
+
+impl<'a> From<&'a [bool]> for Mask<'a> {
+    fn from(bools: &'a [bool]) -> Self {
+        Mask::Bools(bools)
+    }
+}
+
+impl<'a> From<&'a Vec<bool>> for Mask<'a> {
+    fn from(bools: &'a Vec<bool>) -> Self {
+        Mask::Bools(bools)
+    }
+}
+
+impl<'a> From<&'a [u64]> for Mask<'a> {
+    fn from(bits: &'a [u64]) -> Self {
+        Mask::Bits(bits)
+    }
+}
+
+impl<'a> From<&'a Vec<u64>> for Mask<'a> {
+    fn from(bits: &'a Vec<u64>) -> Self {
+        Mask::Bits(bits)
+    }
+}
+
+impl Mask<'_> {
+    /// # Panics
+    ///
+    /// When a `Bools` mask is not exactly `len` long or a `Bits` mask has
+    /// fewer than `len` bits.
+    pub(crate) fn check_len(&self, len: usize) {
+        match self {
+            Mask::Bools(bools) => assert_eq!(bools.len(), len, "mask length must match the input"),
+            Mask::Bits(bits) => assert!(
+                bits.len() >= len.div_ceil(64),
+                "bitmap must hold a bit for every element"
+            ),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get(&self, index: usize) -> bool {
+        match self {
+            Mask::Bools(bools) => bools[index],
+            Mask::Bits(bits) => bits[index / 64] >> (index % 64) & 1 == 1,
+        }
+    }
+
+    /// Selection of the `count` elements from `start` packed into the low bits,
+    /// element `start` in bit 0. `count` is 4 or 8 and divides `start`.
+    #[cfg(target_arch = "x86_64")]
+    #[inline]
+    pub(crate) fn lanes(&self, start: usize, count: usize) -> u32 {
+        debug_assert!((count == 4 || count == 8) && start.is_multiple_of(count));
+        match self {
+            Mask::Bools(bools) => {
+                // A bool is a 0 or 1 byte. The multiplication moves byte `i` to
+                // bit `i` of the top bits without carries between them.
+                let bytes = &as_bytes(bools)[start..start + count];
+                if count == 4 {
+                    let packed = u32::from_le_bytes(bytes.try_into().unwrap());
+                    packed.wrapping_mul(0x1020_4080) >> 28
+                } else {
+                    let packed = u64::from_le_bytes(bytes.try_into().unwrap());
+                    (packed.wrapping_mul(0x0102_0408_1020_4080) >> 56) as u32
+                }
+            }
+            Mask::Bits(bits) => (bits[start / 64] >> (start % 64)) as u32 & ((1 << count) - 1),
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn as_bytes(bools: &[bool]) -> &[u8] {
+    // bool has the size and alignment of u8 and is always 0 or 1.
+    unsafe { &*(bools as *const [bool] as *const [u8]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mask;
+    use rand::{thread_rng, Rng};
+
+    fn pack(bools: &[bool]) -> Vec<u64> {
+        bools
+            .chunks(64)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |word, (i, &b)| word | (b as u64) << i)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bools_and_bits_agree() {
+        let bools: Vec<bool> = (0..256).map(|_| thread_rng().gen()).collect();
+        let bits = pack(&bools);
+
+        #[cfg(target_arch = "x86_64")]
+        for count in [4, 8] {
+            for start in (0..bools.len()).step_by(count) {
+                let expected =
+                    (0..count).fold(0, |lanes, i| lanes | (bools[start + i] as u32) << i);
+                assert_eq!(Mask::Bools(&bools).lanes(start, count), expected);
+                assert_eq!(Mask::Bits(&bits).lanes(start, count), expected);
+            }
+        }
+        for (i, &b) in bools.iter().enumerate() {
+            assert_eq!(Mask::Bits(&bits).get(i), b);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "mask length")]
+    fn test_short_bool_mask_panics() {
+        Mask::Bools(&[true; 3]).check_len(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "bitmap")]
+    fn test_short_bitmap_panics() {
+        Mask::Bits(&[u64::MAX]).check_len(65);
+    }
+}
@@ -0,0 +1,10 @@
+pub mod simd_f32;
+pub mod simd_f64;
+pub mod simd_i16;
+pub mod simd_i32;
+pub mod simd_i64;
+pub mod simd_i8;
+pub mod simd_u16;
+pub mod simd_u32;
+pub mod simd_u64;
+pub mod simd_u8;
@@ -0,0 +1,283 @@
+use crate::generic::{simple_argmax, simple_argmin, simple_argminmax};
+use crate::task::{merge_max, merge_min, split_array, with_value, with_values, MinMaxResult};
+use std::arch::aarch64::*;
+
+pub fn argmin_i16(arr: &[i16]) -> Option<usize> {
+    min_with_index_i16(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_i16(arr: &[i16]) -> Option<(i16, usize)> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result = unsafe { core_argmin(sim, rem.len()) };
+            Some(merge_min(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { core_argmin(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argmin(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    let offset = vdupq_n_u32(rem_offset as u32);
+    let mut index_low_0 = vaddq_u32(vld1q_u32([0, 1, 2, 3].as_ptr()), offset);
+    let mut index_low_1 = vaddq_u32(vld1q_u32([4, 5, 6, 7].as_ptr()), offset);
+    let mut new_index_low_0 = index_low_0;
+    let mut new_index_low_1 = index_low_1;
+
+    let increment = vdupq_n_u32(8);
+
+    let new_values = vld1q_s16(sim_arr.as_ptr());
+    let (mut values_low_0, mut values_low_1) = (
+        vmovl_s16(vget_low_s16(new_values)),
+        vmovl_high_s16(new_values),
+    );
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_low_0 = vaddq_u32(new_index_low_0, increment);
+        new_index_low_1 = vaddq_u32(new_index_low_1, increment);
+
+        let new_values = vld1q_s16(step.as_ptr());
+        let (new_values_0, new_values_1) = (
+            vmovl_s16(vget_low_s16(new_values)),
+            vmovl_high_s16(new_values),
+        );
+        let lt_mask_0 = vcltq_s32(new_values_0, values_low_0);
+        let lt_mask_1 = vcltq_s32(new_values_1, values_low_1);
+
+        values_low_0 = vbslq_s32(lt_mask_0, new_values_0, values_low_0);
+        values_low_1 = vbslq_s32(lt_mask_1, new_values_1, values_low_1);
+        index_low_0 = vbslq_u32(lt_mask_0, new_index_low_0, index_low_0);
+        index_low_1 = vbslq_u32(lt_mask_1, new_index_low_1, index_low_1);
+    });
+
+    horizontal_argmin(values_low_0, values_low_1, index_low_0, index_low_1)
+}
+
+unsafe fn horizontal_argmin(
+    values_low_0: int32x4_t,
+    values_low_1: int32x4_t,
+    index_low_0: uint32x4_t,
+    index_low_1: uint32x4_t,
+) -> (i16, usize) {
+    let value = vminvq_s32(vminq_s32(values_low_0, values_low_1));
+    let low_mask_0 = vceqq_s32(values_low_0, vdupq_n_s32(value));
+    let low_mask_1 = vceqq_s32(values_low_1, vdupq_n_s32(value));
+
+    let out_of_range = vdupq_n_u32(u32::MAX);
+    let index_low = vminq_u32(
+        vbslq_u32(low_mask_0, index_low_0, out_of_range),
+        vbslq_u32(low_mask_1, index_low_1, out_of_range),
+    );
+
+    (value as i16, vminvq_u32(index_low) as usize)
+}
+
+pub fn argmax_i16(arr: &[i16]) -> Option<usize> {
+    max_with_index_i16(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_i16(arr: &[i16]) -> Option<(i16, usize)> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let rem_max_index = simple_argmax(rem);
+            let rem_result = (rem[rem_max_index], rem_max_index);
+            let sim_result = unsafe { core_argmax(sim, rem.len()) };
+            Some(merge_max(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { core_argmax(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argmax(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    let offset = vdupq_n_u32(rem_offset as u32);
+    let mut index_high_0 = vaddq_u32(vld1q_u32([0, 1, 2, 3].as_ptr()), offset);
+    let mut index_high_1 = vaddq_u32(vld1q_u32([4, 5, 6, 7].as_ptr()), offset);
+    let mut new_index_high_0 = index_high_0;
+    let mut new_index_high_1 = index_high_1;
+
+    let increment = vdupq_n_u32(8);
+
+    let new_values = vld1q_s16(sim_arr.as_ptr());
+    let (mut values_high_0, mut values_high_1) = (
+        vmovl_s16(vget_low_s16(new_values)),
+        vmovl_high_s16(new_values),
+    );
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_high_0 = vaddq_u32(new_index_high_0, increment);
+        new_index_high_1 = vaddq_u32(new_index_high_1, increment);
+
+        let new_values = vld1q_s16(step.as_ptr());
+        let (new_values_0, new_values_1) = (
+            vmovl_s16(vget_low_s16(new_values)),
+            vmovl_high_s16(new_values),
+        );
+        let gt_mask_0 = vcgtq_s32(new_values_0, values_high_0);
+        let gt_mask_1 = vcgtq_s32(new_values_1, values_high_1);
+
+        values_high_0 = vbslq_s32(gt_mask_0, new_values_0, values_high_0);
+        values_high_1 = vbslq_s32(gt_mask_1, new_values_1, values_high_1);
+        index_high_0 = vbslq_u32(gt_mask_0, new_index_high_0, index_high_0);
+        index_high_1 = vbslq_u32(gt_mask_1, new_index_high_1, index_high_1);
+    });
+
+    horizontal_argmax(values_high_0, values_high_1, index_high_0, index_high_1)
+}
+
+unsafe fn horizontal_argmax(
+    values_high_0: int32x4_t,
+    values_high_1: int32x4_t,
+    index_high_0: uint32x4_t,
+    index_high_1: uint32x4_t,
+) -> (i16, usize) {
+    let value = vmaxvq_s32(vmaxq_s32(values_high_0, values_high_1));
+    let high_mask_0 = vceqq_s32(values_high_0, vdupq_n_s32(value));
+    let high_mask_1 = vceqq_s32(values_high_1, vdupq_n_s32(value));
+
+    let out_of_range = vdupq_n_u32(u32::MAX);
+    let index_high = vminq_u32(
+        vbslq_u32(high_mask_0, index_high_0, out_of_range),
+        vbslq_u32(high_mask_1, index_high_1, out_of_range),
+    );
+
+    (value as i16, vminvq_u32(index_high) as usize)
+}
+
+pub fn argminmax_i16(arr: &[i16]) -> Option<(usize, usize)> {
+    minmax_with_indices_i16(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_i16(arr: &[i16]) -> Option<MinMaxResult<i16>> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = unsafe { core_argminmax(sim, rem.len()) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => Some(unsafe { core_argminmax(sim, 0) }),
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argminmax(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    let offset = vdupq_n_u32(rem_offset as u32);
+    let mut index_low_0 = vaddq_u32(vld1q_u32([0, 1, 2, 3].as_ptr()), offset);
+    let mut index_low_1 = vaddq_u32(vld1q_u32([4, 5, 6, 7].as_ptr()), offset);
+    let mut index_high_0 = index_low_0;
+    let mut index_high_1 = index_low_1;
+    let mut new_index_0 = index_low_0;
+    let mut new_index_1 = index_low_1;
+
+    let increment = vdupq_n_u32(8);
+
+    let new_values = vld1q_s16(sim_arr.as_ptr());
+    let (mut values_low_0, mut values_low_1) = (
+        vmovl_s16(vget_low_s16(new_values)),
+        vmovl_high_s16(new_values),
+    );
+    let (mut values_high_0, mut values_high_1) = (values_low_0, values_low_1);
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_0 = vaddq_u32(new_index_0, increment);
+        new_index_1 = vaddq_u32(new_index_1, increment);
+
+        let new_values = vld1q_s16(step.as_ptr());
+        let (new_values_0, new_values_1) = (
+            vmovl_s16(vget_low_s16(new_values)),
+            vmovl_high_s16(new_values),
+        );
+        let lt_mask_0 = vcltq_s32(new_values_0, values_low_0);
+        let lt_mask_1 = vcltq_s32(new_values_1, values_low_1);
+        let gt_mask_0 = vcgtq_s32(new_values_0, values_high_0);
+        let gt_mask_1 = vcgtq_s32(new_values_1, values_high_1);
+
+        values_low_0 = vbslq_s32(lt_mask_0, new_values_0, values_low_0);
+        values_low_1 = vbslq_s32(lt_mask_1, new_values_1, values_low_1);
+        index_low_0 = vbslq_u32(lt_mask_0, new_index_0, index_low_0);
+        index_low_1 = vbslq_u32(lt_mask_1, new_index_1, index_low_1);
+
+        values_high_0 = vbslq_s32(gt_mask_0, new_values_0, values_high_0);
+        values_high_1 = vbslq_s32(gt_mask_1, new_values_1, values_high_1);
+        index_high_0 = vbslq_u32(gt_mask_0, new_index_0, index_high_0);
+        index_high_1 = vbslq_u32(gt_mask_1, new_index_1, index_high_1);
+    });
+
+    (
+        horizontal_argmin(values_low_0, values_low_1, index_low_0, index_low_1),
+        horizontal_argmax(values_high_0, values_high_1, index_high_0, index_high_1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{argmax_i16, argmin_i16, argminmax_i16, simple_argmax, simple_argmin};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_i16(n: usize) -> Vec<i16> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(i16::MIN, i16::MAX);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_i16(1025);
+        assert_eq!(data.len() % 8, 1);
+
+        let min_index = argmin_i16(&data).unwrap();
+        let max_index = argmax_i16(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let mut data = vec![1; 1025];
+        data[300] = 0;
+        data[800] = 0;
+        data[17] = 2;
+        data[600] = 2;
+
+        assert_eq!(argmin_i16(&data), Some(300));
+        assert_eq!(argmax_i16(&data), Some(17));
+        assert_eq!(argminmax_i16(&data), Some((300, 17)));
+
+        let data = vec![1; 1025];
+        assert_eq!(argminmax_i16(&data), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [1, 7, 8, 9, 1025] {
+            let data = get_array_i16(n);
+            assert_eq!(
+                argminmax_i16(&data),
+                Some((simple_argmin(&data), simple_argmax(&data)))
+            );
+        }
+    }
+}
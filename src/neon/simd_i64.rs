@@ -0,0 +1,223 @@
+use crate::generic::{simple_argmax, simple_argmin, simple_argminmax};
+use crate::task::{merge_max, merge_min, split_array, with_value, with_values, MinMaxResult};
+use std::arch::aarch64::*;
+
+pub fn argmin_i64(arr: &[i64]) -> Option<usize> {
+    min_with_index_i64(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_i64(arr: &[i64]) -> Option<(i64, usize)> {
+    match split_array(arr, 2) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result = unsafe { core_argmin(sim, rem.len()) };
+            Some(merge_min(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { core_argmin(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argmin(sim_arr: &[i64], rem_offset: usize) -> (i64, usize) {
+    let offset = vdupq_n_u64(rem_offset as u64);
+    let mut index_low = vaddq_u64(vld1q_u64([0, 1].as_ptr()), offset);
+
+    let increment = vdupq_n_u64(2);
+    let mut new_index_low = index_low;
+
+    let mut values_low = vld1q_s64(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index_low = vaddq_u64(new_index_low, increment);
+
+        let new_values = vld1q_s64(step.as_ptr());
+        let lt_mask = vcltq_s64(new_values, values_low);
+
+        values_low = vbslq_s64(lt_mask, new_values, values_low);
+        index_low = vbslq_u64(lt_mask, new_index_low, index_low);
+    });
+
+    horizontal_argmin(values_low, index_low)
+}
+
+unsafe fn horizontal_argmin(values_low: int64x2_t, index_low: uint64x2_t) -> (i64, usize) {
+    let value_array = std::mem::transmute::<int64x2_t, [i64; 2]>(values_low);
+    let index_array = std::mem::transmute::<uint64x2_t, [u64; 2]>(index_low);
+
+    let lane = usize::from(
+        value_array[1] < value_array[0]
+            || (value_array[1] == value_array[0] && index_array[1] < index_array[0]),
+    );
+
+    (value_array[lane], index_array[lane] as usize)
+}
+
+pub fn argmax_i64(arr: &[i64]) -> Option<usize> {
+    max_with_index_i64(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_i64(arr: &[i64]) -> Option<(i64, usize)> {
+    match split_array(arr, 2) {
+        (Some(rem), Some(sim)) => {
+            let rem_max_index = simple_argmax(rem);
+            let rem_result = (rem[rem_max_index], rem_max_index);
+            let sim_result = unsafe { core_argmax(sim, rem.len()) };
+            Some(merge_max(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { core_argmax(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argmax(sim_arr: &[i64], rem_offset: usize) -> (i64, usize) {
+    let offset = vdupq_n_u64(rem_offset as u64);
+    let mut index_high = vaddq_u64(vld1q_u64([0, 1].as_ptr()), offset);
+    let mut new_index_high = index_high;
+
+    let increment = vdupq_n_u64(2);
+
+    let mut values_high = vld1q_s64(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index_high = vaddq_u64(new_index_high, increment);
+
+        let new_values = vld1q_s64(step.as_ptr());
+        let gt_mask = vcgtq_s64(new_values, values_high);
+
+        values_high = vbslq_s64(gt_mask, new_values, values_high);
+        index_high = vbslq_u64(gt_mask, new_index_high, index_high);
+    });
+
+    horizontal_argmax(values_high, index_high)
+}
+
+unsafe fn horizontal_argmax(values_high: int64x2_t, index_high: uint64x2_t) -> (i64, usize) {
+    let value_array = std::mem::transmute::<int64x2_t, [i64; 2]>(values_high);
+    let index_array = std::mem::transmute::<uint64x2_t, [u64; 2]>(index_high);
+
+    let lane = usize::from(
+        value_array[1] > value_array[0]
+            || (value_array[1] == value_array[0] && index_array[1] < index_array[0]),
+    );
+
+    (value_array[lane], index_array[lane] as usize)
+}
+
+pub fn argminmax_i64(arr: &[i64]) -> Option<(usize, usize)> {
+    minmax_with_indices_i64(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_i64(arr: &[i64]) -> Option<MinMaxResult<i64>> {
+    match split_array(arr, 2) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = unsafe { core_argminmax(sim, rem.len()) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => Some(unsafe { core_argminmax(sim, 0) }),
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argminmax(sim_arr: &[i64], rem_offset: usize) -> MinMaxResult<i64> {
+    let offset = vdupq_n_u64(rem_offset as u64);
+    let mut index_low = vaddq_u64(vld1q_u64([0, 1].as_ptr()), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = vdupq_n_u64(2);
+
+    let mut values_low = vld1q_s64(sim_arr.as_ptr());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index = vaddq_u64(new_index, increment);
+
+        let new_values = vld1q_s64(step.as_ptr());
+        let lt_mask = vcltq_s64(new_values, values_low);
+        let gt_mask = vcgtq_s64(new_values, values_high);
+
+        values_low = vbslq_s64(lt_mask, new_values, values_low);
+        index_low = vbslq_u64(lt_mask, new_index, index_low);
+
+        values_high = vbslq_s64(gt_mask, new_values, values_high);
+        index_high = vbslq_u64(gt_mask, new_index, index_high);
+    });
+
+    (
+        horizontal_argmin(values_low, index_low),
+        horizontal_argmax(values_high, index_high),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{argmax_i64, argmin_i64, argminmax_i64, simple_argmax, simple_argmin};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_i64(n: usize) -> Vec<i64> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(i64::MIN, i64::MAX);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_i64(1025);
+        assert_eq!(data.len() % 8, 1);
+
+        let min_index = argmin_i64(&data).unwrap();
+        let max_index = argmax_i64(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let mut data = vec![1; 1025];
+        data[300] = 0;
+        data[800] = 0;
+        data[17] = 2;
+        data[600] = 2;
+
+        assert_eq!(argmin_i64(&data), Some(300));
+        assert_eq!(argmax_i64(&data), Some(17));
+        assert_eq!(argminmax_i64(&data), Some((300, 17)));
+
+        let data = vec![1; 1025];
+        assert_eq!(argminmax_i64(&data), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [1, 7, 8, 9, 1025] {
+            let data = get_array_i64(n);
+            assert_eq!(
+                argminmax_i64(&data),
+                Some((simple_argmin(&data), simple_argmax(&data)))
+            );
+        }
+    }
+}
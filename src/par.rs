@@ -0,0 +1,95 @@
+use rayon::prelude::*;
+
+use crate::task::{merge_max, merge_min};
+use crate::ArgMinMax;
+
+/// Bytes per chunk handed to one task, about the size of a core's L2 cache.
+const CHUNK_BYTES: usize = 256 * 1024;
+
+/// Argmin/argmax spread over the rayon thread pool, for inputs large enough to
+/// be bound by memory bandwidth on a single core. The input is split into
+/// cache-sized chunks that each go through the SIMD kernels, and the results
+/// agree with [`ArgMinMax`], first index on ties included.
+pub trait ParArgMinMax {
+    fn par_argmin(&self) -> Option<usize>;
+    fn par_argmax(&self) -> Option<usize>;
+}
+
+impl<T> ParArgMinMax for [T]
+where
+    T: Copy + PartialOrd + Send + Sync,
+    [T]: ArgMinMax<Value = T>,
+{
+    fn par_argmin(&self) -> Option<usize> {
+        let chunk_len = chunk_len::<T>();
+        if self.len() <= chunk_len {
+            return self.argmin();
+        }
+        self.par_chunks(chunk_len)
+            .enumerate()
+            .filter_map(|(i, chunk)| {
+                let (value, index) = chunk.min_with_index()?;
+                Some((value, i * chunk_len + index))
+            })
+            .reduce_with(merge_min)
+            .map(|(_, index)| index)
+    }
+
+    fn par_argmax(&self) -> Option<usize> {
+        let chunk_len = chunk_len::<T>();
+        if self.len() <= chunk_len {
+            return self.argmax();
+        }
+        self.par_chunks(chunk_len)
+            .enumerate()
+            .filter_map(|(i, chunk)| {
+                let (value, index) = chunk.max_with_index()?;
+                Some((value, i * chunk_len + index))
+            })
+            .reduce_with(merge_max)
+            .map(|(_, index)| index)
+    }
+}
+
+fn chunk_len<T>() -> usize {
+    std::cmp::max(CHUNK_BYTES / std::mem::size_of::<T>(), 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunk_len, ParArgMinMax};
+    use crate::ArgMinMax;
+
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_par_matches_sequential_across_chunks() {
+        macro_rules! check {
+            ($($t:ty),*) => {$(
+                let n = 3 * chunk_len::<$t>() + 12_345;
+                let data: Vec<$t> = (0..n).map(|_| thread_rng().gen()).collect();
+                assert_eq!(data.par_argmin(), data.argmin());
+                assert_eq!(data.par_argmax(), data.argmax());
+                assert_eq!(data[..1000].par_argmin(), data[..1000].argmin());
+            )*};
+        }
+        check!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+        assert_eq!(Vec::<f32>::new().par_argmax(), None);
+    }
+
+    #[test]
+    fn test_par_keeps_first_index_when_extremes_repeat_across_chunks() {
+        let n = 5 * chunk_len::<i32>();
+        let mut data = vec![0i32; n];
+        for i in (1..5).rev() {
+            data[i * chunk_len::<i32>() + 7] = -1;
+            data[i * chunk_len::<i32>() + 9] = 1;
+        }
+        assert_eq!(data.par_argmin(), Some(chunk_len::<i32>() + 7));
+        assert_eq!(data.par_argmax(), Some(chunk_len::<i32>() + 9));
+
+        let constant = vec![3.5f64; n];
+        assert_eq!(constant.par_argmin(), Some(0));
+        assert_eq!(constant.par_argmax(), Some(0));
+    }
+}
@@ -0,0 +1,429 @@
+use crate::generic::{
+    simple_argmax, simple_argmin, simple_argminmax, simple_first_nan, simple_nanargmax,
+    simple_nanargmin,
+};
+use crate::task::{
+    blocked_argmax, blocked_argmin, blocked_argminmax, find_final_index_max, find_final_index_min,
+    merge_max, merge_min, split_array, with_value, with_values, MinMaxResult, I32_BLOCK_SIZE,
+};
+use bytemuck::cast;
+use std::convert::TryInto;
+use wide::*;
+
+pub fn argmin_f32(arr: &[f32]) -> Option<usize> {
+    min_with_index_f32(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_f32(arr: &[f32]) -> Option<(f32, usize)> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result = core_argmin(sim, rem.len());
+            Some(merge_min(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin(rem))),
+        (None, Some(sim)) => {
+            let sim_result = core_argmin(sim, 0);
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+/// Runs the kernel on blocks of [`I32_BLOCK_SIZE`] so the `i32` index lanes
+/// never wrap.
+fn core_argmin(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    unsafe { blocked_argmin::<_, false>(sim_arr, rem_offset, I32_BLOCK_SIZE, core_argmin_block) }
+}
+
+fn core_argmin_block(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_low = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+
+    let increment = i32x8::splat(8);
+    let mut new_index_low = index_low;
+
+    let mut values_low = f32x8::new(sim_arr[..8].try_into().unwrap());
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_low += increment;
+
+        let new_values = f32x8::new(step.try_into().unwrap());
+        let lt_mask = new_values.cmp_lt(values_low);
+
+        values_low = lt_mask.blend(new_values, values_low);
+        index_low = cast::<f32x8, i32x8>(lt_mask).blend(new_index_low, index_low);
+    });
+
+    horizontal_argmin(values_low, index_low)
+}
+
+fn horizontal_argmin(values_low: f32x8, index_low: i32x8) -> (f32, usize) {
+    let value_array = values_low.to_array();
+    let index_array = index_low.to_array();
+
+    let min_index = simple_argmin(&value_array);
+    let value = value_array[min_index];
+    let index = value_array
+        .iter()
+        .zip(index_array.iter())
+        .filter(|(lane_value, _)| **lane_value == value)
+        .fold(index_array[min_index], |low, (_, lane_index)| {
+            low.min(*lane_index)
+        });
+
+    (value, index as usize)
+}
+
+pub fn argmax_f32(arr: &[f32]) -> Option<usize> {
+    max_with_index_f32(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_f32(arr: &[f32]) -> Option<(f32, usize)> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let rem_max_index = simple_argmax(rem);
+            let rem_result = (rem[rem_max_index], rem_max_index);
+            let sim_result = core_argmax(sim, rem.len());
+            Some(merge_max(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax(rem))),
+        (None, Some(sim)) => {
+            let sim_result = core_argmax(sim, 0);
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+fn core_argmax(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    unsafe { blocked_argmax::<_, false>(sim_arr, rem_offset, I32_BLOCK_SIZE, core_argmax_block) }
+}
+
+fn core_argmax_block(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_high = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+    let mut new_index_high = index_high;
+
+    let increment = i32x8::splat(8);
+
+    let mut values_high = f32x8::new(sim_arr[..8].try_into().unwrap());
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_high += increment;
+
+        let new_values = f32x8::new(step.try_into().unwrap());
+        let gt_mask = new_values.cmp_gt(values_high);
+
+        values_high = gt_mask.blend(new_values, values_high);
+        index_high = cast::<f32x8, i32x8>(gt_mask).blend(new_index_high, index_high);
+    });
+
+    horizontal_argmax(values_high, index_high)
+}
+
+fn horizontal_argmax(values_high: f32x8, index_high: i32x8) -> (f32, usize) {
+    let value_array = values_high.to_array();
+    let index_array = index_high.to_array();
+
+    let max_index = simple_argmax(&value_array);
+    let value = value_array[max_index];
+    let index = value_array
+        .iter()
+        .zip(index_array.iter())
+        .filter(|(lane_value, _)| **lane_value == value)
+        .fold(index_array[max_index], |low, (_, lane_index)| {
+            low.min(*lane_index)
+        });
+
+    (value, index as usize)
+}
+
+pub fn argminmax_f32(arr: &[f32]) -> Option<(usize, usize)> {
+    minmax_with_indices_f32(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_f32(arr: &[f32]) -> Option<MinMaxResult<f32>> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = core_argminmax(sim, rem.len());
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => Some(core_argminmax(sim, 0)),
+        (None, None) => None,
+    }
+}
+
+fn core_argminmax(sim_arr: &[f32], rem_offset: usize) -> MinMaxResult<f32> {
+    unsafe { blocked_argminmax(sim_arr, rem_offset, I32_BLOCK_SIZE, core_argminmax_block) }
+}
+
+fn core_argminmax_block(sim_arr: &[f32], rem_offset: usize) -> MinMaxResult<f32> {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_low = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = i32x8::splat(8);
+
+    let mut values_low = f32x8::new(sim_arr[..8].try_into().unwrap());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index += increment;
+
+        let new_values = f32x8::new(step.try_into().unwrap());
+        let lt_mask = new_values.cmp_lt(values_low);
+        let gt_mask = new_values.cmp_gt(values_high);
+
+        values_low = lt_mask.blend(new_values, values_low);
+        index_low = cast::<f32x8, i32x8>(lt_mask).blend(new_index, index_low);
+
+        values_high = gt_mask.blend(new_values, values_high);
+        index_high = cast::<f32x8, i32x8>(gt_mask).blend(new_index, index_high);
+    });
+
+    (
+        horizontal_argmin(values_low, index_low),
+        horizontal_argmax(values_high, index_high),
+    )
+}
+
+pub fn nanargmin_f32(arr: &[f32]) -> Option<usize> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let sim_result = core_nanargmin(sim, rem.len());
+            if sim_result.0 == f32::INFINITY {
+                return simple_nanargmin(arr);
+            }
+            match simple_nanargmin(rem) {
+                Some(rem_min_index) => {
+                    let rem_result = (rem[rem_min_index], rem_min_index);
+                    find_final_index_min(rem_result, sim_result)
+                }
+                None => Some(sim_result.1),
+            }
+        }
+        (Some(rem), None) => simple_nanargmin(rem),
+        (None, Some(sim)) => {
+            let sim_result = core_nanargmin(sim, 0);
+            if sim_result.0 == f32::INFINITY {
+                return simple_nanargmin(sim);
+            }
+            Some(sim_result.1)
+        }
+        (None, None) => None,
+    }
+}
+
+fn core_nanargmin(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    unsafe { blocked_argmin::<_, false>(sim_arr, rem_offset, I32_BLOCK_SIZE, core_nanargmin_block) }
+}
+
+/// NaN lanes are replaced with +inf, so a winning value of +inf is ambiguous
+/// and the caller falls back to a scalar scan.
+fn core_nanargmin_block(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_low = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+
+    let increment = i32x8::splat(8);
+    let mut new_index_low = index_low;
+
+    let replacement = f32x8::splat(f32::INFINITY);
+    let mut values_low = replace_nan(f32x8::new(sim_arr[..8].try_into().unwrap()), replacement);
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_low += increment;
+
+        let new_values = replace_nan(f32x8::new(step.try_into().unwrap()), replacement);
+        let lt_mask = new_values.cmp_lt(values_low);
+
+        values_low = lt_mask.blend(new_values, values_low);
+        index_low = cast::<f32x8, i32x8>(lt_mask).blend(new_index_low, index_low);
+    });
+
+    horizontal_argmin(values_low, index_low)
+}
+
+pub fn nanargmax_f32(arr: &[f32]) -> Option<usize> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let sim_result = core_nanargmax(sim, rem.len());
+            if sim_result.0 == f32::NEG_INFINITY {
+                return simple_nanargmax(arr);
+            }
+            match simple_nanargmax(rem) {
+                Some(rem_max_index) => {
+                    let rem_result = (rem[rem_max_index], rem_max_index);
+                    find_final_index_max(rem_result, sim_result)
+                }
+                None => Some(sim_result.1),
+            }
+        }
+        (Some(rem), None) => simple_nanargmax(rem),
+        (None, Some(sim)) => {
+            let sim_result = core_nanargmax(sim, 0);
+            if sim_result.0 == f32::NEG_INFINITY {
+                return simple_nanargmax(sim);
+            }
+            Some(sim_result.1)
+        }
+        (None, None) => None,
+    }
+}
+
+fn core_nanargmax(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    unsafe { blocked_argmax::<_, false>(sim_arr, rem_offset, I32_BLOCK_SIZE, core_nanargmax_block) }
+}
+
+/// NaN lanes are replaced with -inf, so a winning value of -inf is ambiguous
+/// and the caller falls back to a scalar scan.
+fn core_nanargmax_block(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_high = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+    let mut new_index_high = index_high;
+
+    let increment = i32x8::splat(8);
+
+    let replacement = f32x8::splat(f32::NEG_INFINITY);
+    let mut values_high = replace_nan(f32x8::new(sim_arr[..8].try_into().unwrap()), replacement);
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_high += increment;
+
+        let new_values = replace_nan(f32x8::new(step.try_into().unwrap()), replacement);
+        let gt_mask = new_values.cmp_gt(values_high);
+
+        values_high = gt_mask.blend(new_values, values_high);
+        index_high = cast::<f32x8, i32x8>(gt_mask).blend(new_index_high, index_high);
+    });
+
+    horizontal_argmax(values_high, index_high)
+}
+
+fn replace_nan(values: f32x8, replacement: f32x8) -> f32x8 {
+    values.is_nan().blend(replacement, values)
+}
+
+pub fn first_nan_f32(arr: &[f32]) -> Option<usize> {
+    let chunks = arr.chunks_exact(8);
+    let tail = chunks.remainder();
+
+    for (i, step) in chunks.enumerate() {
+        let values = f32x8::new(step.try_into().unwrap());
+        if values.is_nan().any() {
+            return simple_first_nan(step).map(|index| i * 8 + index);
+        }
+    }
+
+    simple_first_nan(tail).map(|index| arr.len() - tail.len() + index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        argmax_f32, argmin_f32, argminmax_f32, core_argmax_block, core_argmin_block,
+        core_argminmax_block, core_nanargmax_block, core_nanargmin_block, first_nan_f32,
+        nanargmax_f32, nanargmin_f32, simple_argmax, simple_argmin, simple_nanargmax,
+        simple_nanargmin,
+    };
+    use crate::task::{blocked_argmax, blocked_argmin, blocked_argminmax};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_f32(n: usize) -> Vec<f32> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(f32::MIN / 4.0, f32::MAX / 4.0);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_f32(1025);
+        assert_eq!(data.len() % 8, 1);
+
+        let min_index = argmin_f32(&data).unwrap();
+        let max_index = argmax_f32(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_blocked_kernels_rebase_indices_across_blocks() {
+        // Few distinct values, so the extremes tie across block boundaries.
+        let data: Vec<f32> = (0..328)
+            .map(|_| thread_rng().gen_range(0..4) as f32)
+            .collect();
+        let offset = 3;
+        let (min, max) = (simple_argmin(&data), simple_argmax(&data));
+
+        unsafe {
+            let (_, low) = blocked_argmin::<_, false>(&data, offset, 64, core_argmin_block);
+            let (_, high) = blocked_argmax::<_, false>(&data, offset, 64, core_argmax_block);
+            assert_eq!((low, high), (offset + min, offset + max));
+
+            let ((_, low), (_, high)) = blocked_argminmax(&data, offset, 64, core_argminmax_block);
+            assert_eq!((low, high), (offset + min, offset + max));
+
+            let (_, low) = blocked_argmin::<_, false>(&data, offset, 64, core_nanargmin_block);
+            let (_, high) = blocked_argmax::<_, false>(&data, offset, 64, core_nanargmax_block);
+            assert_eq!((low, high), (offset + min, offset + max));
+        }
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let mut data = vec![1.0; 1025];
+        data[300] = 0.0;
+        data[800] = 0.0;
+        data[17] = 2.0;
+        data[600] = 2.0;
+
+        assert_eq!(argmin_f32(&data), Some(300));
+        assert_eq!(argmax_f32(&data), Some(17));
+        assert_eq!(argminmax_f32(&data), Some((300, 17)));
+
+        let data = vec![1.0; 1025];
+        assert_eq!(argminmax_f32(&data), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [1, 7, 8, 9, 1025] {
+            let data = get_array_f32(n);
+            assert_eq!(
+                argminmax_f32(&data),
+                Some((simple_argmin(&data), simple_argmax(&data)))
+            );
+        }
+    }
+
+    #[test]
+    fn test_nan_is_ignored_in_remainder_and_simd_body() {
+        let mut data = get_array_f32(1027);
+        data[1] = f32::NAN;
+        data[500] = f32::NAN;
+
+        let min_index = nanargmin_f32(&data).unwrap();
+        let max_index = nanargmax_f32(&data).unwrap();
+
+        assert_eq!(Some(min_index), simple_nanargmin(&data));
+        assert_eq!(Some(max_index), simple_nanargmax(&data));
+        assert_eq!(first_nan_f32(&data), Some(1));
+        assert_eq!(first_nan_f32(&data[3..]), Some(497));
+    }
+}
@@ -0,0 +1,251 @@
+use crate::generic::{simple_argmax, simple_argmin, simple_argminmax};
+use crate::task::{
+    blocked_argmax, blocked_argmin, blocked_argminmax, merge_max, merge_min, split_array,
+    with_value, with_values, MinMaxResult, I32_BLOCK_SIZE,
+};
+use std::convert::TryInto;
+use wide::*;
+
+pub fn argmin_i32(arr: &[i32]) -> Option<usize> {
+    min_with_index_i32(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_i32(arr: &[i32]) -> Option<(i32, usize)> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result = core_argmin(sim, rem.len());
+            Some(merge_min(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin(rem))),
+        (None, Some(sim)) => {
+            let sim_result = core_argmin(sim, 0);
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+/// Runs the kernel on blocks of [`I32_BLOCK_SIZE`] so the `i32` index lanes
+/// never wrap.
+fn core_argmin(sim_arr: &[i32], rem_offset: usize) -> (i32, usize) {
+    unsafe { blocked_argmin::<_, false>(sim_arr, rem_offset, I32_BLOCK_SIZE, core_argmin_block) }
+}
+
+fn core_argmin_block(sim_arr: &[i32], rem_offset: usize) -> (i32, usize) {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_low = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+
+    let increment = i32x8::splat(8);
+    let mut new_index_low = index_low;
+
+    let mut values_low = i32x8::new(sim_arr[..8].try_into().unwrap());
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_low += increment;
+
+        let new_values = i32x8::new(step.try_into().unwrap());
+        let lt_mask = new_values.cmp_lt(values_low);
+
+        values_low = lt_mask.blend(new_values, values_low);
+        index_low = lt_mask.blend(new_index_low, index_low);
+    });
+
+    horizontal_argmin(values_low, index_low)
+}
+
+fn horizontal_argmin(values_low: i32x8, index_low: i32x8) -> (i32, usize) {
+    let value_array = values_low.to_array();
+    let index_array = index_low.to_array();
+
+    let min_index = simple_argmin(&value_array);
+    let value = value_array[min_index];
+    let index = value_array
+        .iter()
+        .zip(index_array.iter())
+        .filter(|(lane_value, _)| **lane_value == value)
+        .fold(index_array[min_index], |low, (_, lane_index)| {
+            low.min(*lane_index)
+        });
+
+    (value, index as usize)
+}
+
+pub fn argmax_i32(arr: &[i32]) -> Option<usize> {
+    max_with_index_i32(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_i32(arr: &[i32]) -> Option<(i32, usize)> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let rem_max_index = simple_argmax(rem);
+            let rem_result = (rem[rem_max_index], rem_max_index);
+            let sim_result = core_argmax(sim, rem.len());
+            Some(merge_max(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax(rem))),
+        (None, Some(sim)) => {
+            let sim_result = core_argmax(sim, 0);
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+fn core_argmax(sim_arr: &[i32], rem_offset: usize) -> (i32, usize) {
+    unsafe { blocked_argmax::<_, false>(sim_arr, rem_offset, I32_BLOCK_SIZE, core_argmax_block) }
+}
+
+fn core_argmax_block(sim_arr: &[i32], rem_offset: usize) -> (i32, usize) {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_high = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+    let mut new_index_high = index_high;
+
+    let increment = i32x8::splat(8);
+
+    let mut values_high = i32x8::new(sim_arr[..8].try_into().unwrap());
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_high += increment;
+
+        let new_values = i32x8::new(step.try_into().unwrap());
+        let gt_mask = new_values.cmp_gt(values_high);
+
+        values_high = gt_mask.blend(new_values, values_high);
+        index_high = gt_mask.blend(new_index_high, index_high);
+    });
+
+    horizontal_argmax(values_high, index_high)
+}
+
+fn horizontal_argmax(values_high: i32x8, index_high: i32x8) -> (i32, usize) {
+    let value_array = values_high.to_array();
+    let index_array = index_high.to_array();
+
+    let max_index = simple_argmax(&value_array);
+    let value = value_array[max_index];
+    let index = value_array
+        .iter()
+        .zip(index_array.iter())
+        .filter(|(lane_value, _)| **lane_value == value)
+        .fold(index_array[max_index], |low, (_, lane_index)| {
+            low.min(*lane_index)
+        });
+
+    (value, index as usize)
+}
+
+pub fn argminmax_i32(arr: &[i32]) -> Option<(usize, usize)> {
+    minmax_with_indices_i32(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_i32(arr: &[i32]) -> Option<MinMaxResult<i32>> {
+    match split_array(arr, 8) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = core_argminmax(sim, rem.len());
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => Some(core_argminmax(sim, 0)),
+        (None, None) => None,
+    }
+}
+
+fn core_argminmax(sim_arr: &[i32], rem_offset: usize) -> MinMaxResult<i32> {
+    unsafe { blocked_argminmax(sim_arr, rem_offset, I32_BLOCK_SIZE, core_argminmax_block) }
+}
+
+fn core_argminmax_block(sim_arr: &[i32], rem_offset: usize) -> MinMaxResult<i32> {
+    let offset = i32x8::splat(rem_offset as i32);
+    let mut index_low = i32x8::new([0, 1, 2, 3, 4, 5, 6, 7]) + offset;
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = i32x8::splat(8);
+
+    let mut values_low = i32x8::new(sim_arr[..8].try_into().unwrap());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index += increment;
+
+        let new_values = i32x8::new(step.try_into().unwrap());
+        let lt_mask = new_values.cmp_lt(values_low);
+        let gt_mask = new_values.cmp_gt(values_high);
+
+        values_low = lt_mask.blend(new_values, values_low);
+        index_low = lt_mask.blend(new_index, index_low);
+
+        values_high = gt_mask.blend(new_values, values_high);
+        index_high = gt_mask.blend(new_index, index_high);
+    });
+
+    (
+        horizontal_argmin(values_low, index_low),
+        horizontal_argmax(values_high, index_high),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{argmax_i32, argmin_i32, argminmax_i32, simple_argmax, simple_argmin};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_i32(n: usize) -> Vec<i32> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(i32::MIN, i32::MAX);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_i32(1025);
+        assert_eq!(data.len() % 8, 1);
+
+        let min_index = argmin_i32(&data).unwrap();
+        let max_index = argmax_i32(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let mut data = vec![1; 1025];
+        data[300] = 0;
+        data[800] = 0;
+        data[17] = 2;
+        data[600] = 2;
+
+        assert_eq!(argmin_i32(&data), Some(300));
+        assert_eq!(argmax_i32(&data), Some(17));
+        assert_eq!(argminmax_i32(&data), Some((300, 17)));
+
+        let data = vec![1; 1025];
+        assert_eq!(argminmax_i32(&data), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [1, 7, 8, 9, 1025] {
+            let data = get_array_i32(n);
+            assert_eq!(
+                argminmax_i32(&data),
+                Some((simple_argmin(&data), simple_argmax(&data)))
+            );
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+
+/// Widest window handled by the van Herk/Gil-Werman pass; the timings of both
+/// passes are recorded in `benches/results`. Its four buffers hold a whole
+/// window each, whereas the deque only keeps the indices that can still win,
+/// so wider windows use the deque.
+const BLOCKED_MAX_WINDOW: usize = 1 << 15;
+
+/// Index of the smallest value in every window `data[i..i + w]`, so the result
+/// has `data.len() - w + 1` entries, or none when `data` is shorter than `w`.
+/// Ties go to the first index in the window.
+///
+/// # Panics
+///
+/// When `w` is zero.
+pub fn rolling_argmin<T: Copy + PartialOrd>(data: &[T], w: usize) -> Vec<usize> {
+    rolling::<T, false>(data, w)
+}
+
+/// Index of the largest value in every window `data[i..i + w]`, see
+/// [`rolling_argmin`].
+///
+/// # Panics
+///
+/// When `w` is zero.
+pub fn rolling_argmax<T: Copy + PartialOrd>(data: &[T], w: usize) -> Vec<usize> {
+    rolling::<T, true>(data, w)
+}
+
+#[inline]
+fn rolling<T: Copy + PartialOrd, const LARGEST: bool>(data: &[T], w: usize) -> Vec<usize> {
+    assert!(w > 0, "window must not be empty");
+    if data.len() < w {
+        return Vec::new();
+    }
+    if w <= BLOCKED_MAX_WINDOW {
+        rolling_blocked::<T, LARGEST>(data, w)
+    } else {
+        rolling_deque::<T, LARGEST>(data, w)
+    }
+}
+
+/// Whether `a` is strictly more extreme than `b`.
+#[inline(always)]
+fn beats<T: PartialOrd, const LARGEST: bool>(a: T, b: T) -> bool {
+    if LARGEST {
+        a > b
+    } else {
+        a < b
+    }
+}
+
+/// Keeps the indices of the window that can still become its extremum, from
+/// best to worst. An equal value does not evict an earlier one, so the front
+/// is always the first extremum of the window.
+fn rolling_deque<T: Copy + PartialOrd, const LARGEST: bool>(data: &[T], w: usize) -> Vec<usize> {
+    let mut out = Vec::with_capacity(data.len() - w + 1);
+    let mut deque: VecDeque<usize> = VecDeque::with_capacity(w);
+    for (i, &item) in data.iter().enumerate() {
+        while deque
+            .back()
+            .is_some_and(|&back| beats::<T, LARGEST>(item, data[back]))
+        {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+        if i >= w && deque[0] == i - w {
+            deque.pop_front();
+        }
+        if i + 1 >= w {
+            out.push(deque[0]);
+        }
+    }
+    out
+}
+
+/// van Herk/Gil-Werman: the input is cut into blocks of `w`, so every window
+/// is a suffix of one block followed by a prefix of the next. With the suffix
+/// and prefix extremes of both blocks at hand each window costs one compare.
+fn rolling_blocked<T: Copy + PartialOrd, const LARGEST: bool>(data: &[T], w: usize) -> Vec<usize> {
+    let windows = data.len() - w + 1;
+    let mut out = Vec::with_capacity(windows);
+    let mut suffix_value: Vec<T> = Vec::with_capacity(w);
+    let mut suffix_index: Vec<usize> = Vec::with_capacity(w);
+    let mut prefix_value: Vec<T> = Vec::with_capacity(w);
+    let mut prefix_index: Vec<usize> = Vec::with_capacity(w);
+
+    for start in (0..windows).step_by(w) {
+        // Extremes of block[i..], the earlier index kept on ties.
+        suffix_value.clear();
+        suffix_value.extend_from_slice(&data[start..start + w]);
+        suffix_index.clear();
+        suffix_index.extend(start..start + w);
+        for i in (0..w - 1).rev() {
+            if beats::<T, LARGEST>(suffix_value[i + 1], suffix_value[i]) {
+                suffix_value[i] = suffix_value[i + 1];
+                suffix_index[i] = suffix_index[i + 1];
+            }
+        }
+
+        // Windows starting in this block, the first of which is the block.
+        let count = std::cmp::min(w, windows - start);
+        out.push(suffix_index[0]);
+        if count == 1 {
+            continue;
+        }
+
+        // Extremes of next[..=i], only as far as the last window reaches.
+        let next = start + w;
+        prefix_value.clear();
+        prefix_value.extend_from_slice(&data[next..next + count - 1]);
+        prefix_index.clear();
+        prefix_index.extend(next..next + count - 1);
+        for i in 1..count - 1 {
+            if !beats::<T, LARGEST>(prefix_value[i], prefix_value[i - 1]) {
+                prefix_value[i] = prefix_value[i - 1];
+                prefix_index[i] = prefix_index[i - 1];
+            }
+        }
+
+        // Window `start + r` is suffix `r` followed by prefix `r - 1`.
+        out.extend((1..count).map(|r| {
+            if beats::<T, LARGEST>(prefix_value[r - 1], suffix_value[r]) {
+                prefix_index[r - 1]
+            } else {
+                suffix_index[r]
+            }
+        }));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rolling_argmax, rolling_argmin, rolling_blocked, rolling_deque};
+    use crate::generic::{simple_argmax, simple_argmin};
+
+    use rand::{thread_rng, Rng};
+
+    fn brute_force(data: &[i16], w: usize, largest: bool) -> Vec<usize> {
+        data.windows(w)
+            .enumerate()
+            .map(|(i, window)| {
+                i + if largest {
+                    simple_argmax(window)
+                } else {
+                    simple_argmin(window)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_deque_and_blocked_match_brute_force() {
+        // Few distinct values, so most windows hold tied extremes.
+        let data: Vec<i16> = (0..1_009).map(|_| thread_rng().gen_range(0..8)).collect();
+        for w in [1, 2, 3, 7, 63, 64, 65, 200, 1_008, 1_009] {
+            let expected_min = brute_force(&data, w, false);
+            let expected_max = brute_force(&data, w, true);
+            assert_eq!(rolling_deque::<_, false>(&data, w), expected_min);
+            assert_eq!(rolling_deque::<_, true>(&data, w), expected_max);
+            assert_eq!(rolling_blocked::<_, false>(&data, w), expected_min);
+            assert_eq!(rolling_blocked::<_, true>(&data, w), expected_max);
+        }
+    }
+
+    #[test]
+    fn test_rolling_for_every_type() {
+        macro_rules! check {
+            ($($t:ty),*) => {$(
+                let data: Vec<$t> = (0..500).map(|_| thread_rng().gen()).collect();
+                for w in [5, 100] {
+                    let windows = data.windows(w).enumerate();
+                    let min: Vec<usize> =
+                        windows.clone().map(|(i, window)| i + simple_argmin(window)).collect();
+                    let max: Vec<usize> =
+                        windows.map(|(i, window)| i + simple_argmax(window)).collect();
+                    assert_eq!(rolling_argmin(&data, w), min);
+                    assert_eq!(rolling_argmax(&data, w), max);
+                }
+            )*};
+        }
+        check!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+    }
+
+    #[test]
+    fn test_rolling_short_input() {
+        assert_eq!(rolling_argmax(&[1u8, 2, 3], 4), Vec::<usize>::new());
+        assert_eq!(rolling_argmin(&[2.0f64, 1.0, 3.0], 3), vec![1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "window must not be empty")]
+    fn test_rolling_panics_on_zero_window() {
+        rolling_argmin(&[1i32], 0);
+    }
+}
@@ -1,10 +1,97 @@
 pub mod simd_f32;
-pub use simd_f32::*;
-pub mod simd_i32;
-pub use simd_i32::*;
+pub mod simd_f64;
 pub mod simd_i16;
-pub use simd_i16::*;
+pub mod simd_i32;
+pub mod simd_i64;
+pub mod simd_i8;
 pub mod simd_u16;
-pub use simd_u16::*;
+pub mod simd_u32;
+pub mod simd_u64;
 pub mod simd_u8;
-pub use simd_u8::*;
+
+use std::arch::x86_64::*;
+
+/// Expands the low four bits of `bits` into all-ones or all-zero 32-bit lanes,
+/// `lane_bits` holding 1, 2, 4 and 8 from the lowest lane up.
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn lane_mask(bits: u32, lane_bits: __m128i) -> __m128i {
+    _mm_cmpeq_epi32(
+        _mm_and_si128(_mm_set1_epi32(bits as i32), lane_bits),
+        lane_bits,
+    )
+}
+
+/// Eight-lane version of [`lane_mask`], `lane_bits` holding 1, 2, ..., 128.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn lane_mask_avx2(bits: u32, lane_bits: __m256i) -> __m256i {
+    _mm256_cmpeq_epi32(
+        _mm256_and_si256(_mm256_set1_epi32(bits as i32), lane_bits),
+        lane_bits,
+    )
+}
+
+#[cfg(test)]
+pub(crate) type MaskedKernel<T> =
+    unsafe fn(&[T], crate::Mask, usize) -> crate::task::MaskedResult<T>;
+
+/// Checks the masked kernels and entry points of one element type against the
+/// scalar masked scan, on random and on heavily tied data at several mask
+/// densities. `kernels` lists `(lanes, argmin, argmax)` for every masked kernel
+/// the host can run; each one is handed the input cut down to whole vectors,
+/// whole and split into blocks of 64 elements.
+#[cfg(test)]
+pub(crate) fn check_masked_kernels<T: Copy + PartialOrd + std::fmt::Debug>(
+    get_array: fn(usize) -> Vec<T>,
+    tie: fn(T) -> T,
+    kernels: &[(usize, MaskedKernel<T>, MaskedKernel<T>)],
+    argmin_masked: fn(&[T], crate::Mask) -> Option<usize>,
+    argmax_masked: fn(&[T], crate::Mask) -> Option<usize>,
+) {
+    use crate::generic::{simple_argmax_masked, simple_argmin_masked};
+    use crate::task::{blocked_masked, merge_max, merge_min};
+    use crate::Mask;
+    use rand::{thread_rng, Rng};
+
+    let mut rng = thread_rng();
+    for n in [0, 3, 8, 1000, 1027] {
+        let random = get_array(n);
+        let tied: Vec<T> = random.iter().copied().map(tie).collect();
+        for data in [random, tied] {
+            for density in [0.0, 0.05, 0.5, 1.0] {
+                let bools: Vec<bool> = (0..n).map(|_| rng.gen_bool(density)).collect();
+                let mut bits = vec![0u64; n.div_ceil(64)];
+                for (i, _) in bools.iter().enumerate().filter(|(_, &b)| b) {
+                    bits[i / 64] |= 1 << (i % 64);
+                }
+
+                for &(lanes, kernel_min, kernel_max) in kernels {
+                    let body = &data[..n - n % lanes];
+                    let mask = Mask::Bools(&bools);
+                    let body_mask = Mask::Bools(&bools[..body.len()]);
+                    let min_index = simple_argmin_masked(body, body_mask);
+                    let max_index = simple_argmax_masked(body, body_mask);
+                    let (min, max) =
+                        unsafe { (kernel_min(body, mask, 0), kernel_max(body, mask, 0)) };
+                    assert_eq!(min.map(|(_, index)| index), min_index);
+                    assert_eq!(max.map(|(_, index)| index), max_index);
+
+                    let (min, max) = unsafe {
+                        (
+                            blocked_masked(body, mask, 64, kernel_min, merge_min),
+                            blocked_masked(body, mask, 64, kernel_max, merge_max),
+                        )
+                    };
+                    assert_eq!(min.map(|(_, index)| index), min_index);
+                    assert_eq!(max.map(|(_, index)| index), max_index);
+                }
+
+                let min_index = simple_argmin_masked(&data, Mask::Bools(&bools));
+                let max_index = simple_argmax_masked(&data, Mask::Bools(&bools));
+                for mask in [Mask::Bools(&bools), Mask::Bits(&bits)] {
+                    assert_eq!(argmin_masked(&data, mask), min_index);
+                    assert_eq!(argmax_masked(&data, mask), max_index);
+                }
+            }
+        }
+    }
+}
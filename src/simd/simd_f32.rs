@@ -1,46 +1,101 @@
-use crate::generic::{simple_argmax, simple_argmin};
-use crate::task::{find_final_index_max, find_final_index_min, split_array};
+use super::{lane_mask, lane_mask_avx2};
+use crate::dispatch::{simd_level, SimdLevel};
+use crate::generic::{
+    masked_argmax_from, masked_argmin_from, simple_argmax, simple_argmax_strided, simple_argmin,
+    simple_argmin_strided, simple_argminmax, simple_first_nan, simple_max_with_index_masked,
+    simple_min_with_index_masked, simple_nanargmax, simple_nanargmin,
+};
+use crate::task::{
+    blocked_argmax, blocked_argmin, blocked_argminmax, blocked_gathers, blocked_masked,
+    deinterleaved_argmax, deinterleaved_argmin, find_final_index_max, find_final_index_min,
+    merge_max, merge_max_tie, merge_min, merge_min_tie, simple_argmax_tie, simple_argmin_tie,
+    split_array, split_gathers, with_value, with_values, MaskedResult, MinMaxResult,
+    I32_BLOCK_SIZE, MAX_GATHER_STRIDE,
+};
+use crate::Mask;
 use std::arch::x86_64::*;
 
 pub fn argmin_f32(arr: &[f32]) -> Option<usize> {
-    match split_array(arr, 4) {
+    min_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmin_f32`], but the last index is returned among equal minima.
+pub fn argmin_f32_last(arr: &[f32]) -> Option<usize> {
+    min_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_f32(arr: &[f32]) -> Option<(f32, usize)> {
+    min_with_ties::<false>(arr)
+}
+
+fn min_with_ties<const LAST: bool>(arr: &[f32]) -> Option<(f32, usize)> {
+    if simd_level() == SimdLevel::Avx512 {
+        return (!arr.is_empty()).then(|| {
+            let kernel = |block: &[f32], _: usize| unsafe { core_argmin_avx512::<LAST>(block) };
+            unsafe { blocked_argmin::<_, LAST>(arr, 0, I32_BLOCK_SIZE, kernel) }
+        });
+    }
+
+    let mut simd_func: unsafe fn(&[f32], usize) -> (f32, usize) = core_argmin::<LAST>;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_avx2::<LAST>;
+        mod_size = 8;
+    };
+
+    match split_array(arr, mod_size) {
         (Some(rem), Some(sim)) => {
-            let rem_min_index = simple_argmin(rem);
+            let rem_min_index = simple_argmin_tie::<_, LAST>(rem);
             let rem_result = (rem[rem_min_index], rem_min_index);
-            let sim_result = unsafe { core_argmin(sim, rem.len()) };
-            find_final_index_min(rem_result, sim_result)
+            let sim_result =
+                unsafe { blocked_argmin::<_, LAST>(sim, rem.len(), I32_BLOCK_SIZE, simd_func) };
+            Some(merge_min_tie::<_, LAST>(rem_result, sim_result))
         }
-        (Some(rem), None) => Some(simple_argmin(rem)),
+        (Some(rem), None) => Some(with_value(rem, simple_argmin_tie::<_, LAST>(rem))),
         (None, Some(sim)) => {
-            let sim_result = unsafe { core_argmin(sim, 0) };
-            Some(sim_result.1)
+            let sim_result =
+                unsafe { blocked_argmin::<_, LAST>(sim, 0, I32_BLOCK_SIZE, simd_func) };
+            Some(sim_result)
         }
         (None, None) => None,
     }
 }
 
-unsafe fn core_argmin(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
-    let offset = _mm_set1_ps(rem_offset as f32);
-    let mut index_low = _mm_add_ps(_mm_set_ps(3.0, 2.0, 1.0, 0.0), offset);
+unsafe fn core_argmin<const LAST: bool>(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
 
-    let increment = _mm_set1_ps(4.0);
+    let increment = _mm_set1_epi32(4);
     let mut new_index_low = index_low;
 
-    let mut values_low = _mm_loadu_ps(sim_arr.as_ptr() as *const f32);
+    let mut values_low = _mm_loadu_ps(sim_arr.as_ptr());
 
     sim_arr.chunks_exact(4).skip(1).for_each(|step| {
-        new_index_low = _mm_add_ps(new_index_low, increment);
+        new_index_low = _mm_add_epi32(new_index_low, increment);
 
-        let new_values = _mm_loadu_ps(step.as_ptr() as *const f32);
-        let lt_mask = _mm_cmplt_ps(new_values, values_low);
+        let new_values = _mm_loadu_ps(step.as_ptr());
+        let lt_mask = if LAST {
+            _mm_castps_si128(_mm_cmple_ps(new_values, values_low))
+        } else {
+            _mm_castps_si128(_mm_cmplt_ps(new_values, values_low))
+        };
 
         values_low = _mm_min_ps(new_values, values_low);
-        index_low = _mm_or_ps(
-            _mm_and_ps(new_index_low, lt_mask),
-            _mm_andnot_ps(lt_mask, index_low),
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index_low, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
         );
     });
 
+    horizontal_argmin::<LAST>(values_low, index_low)
+}
+
+unsafe fn horizontal_argmin<const LAST: bool>(
+    values_low: __m128,
+    index_low: __m128i,
+) -> (f32, usize) {
     let highpack = _mm_unpackhi_ps(values_low, values_low);
     let lowpack = _mm_unpacklo_ps(values_low, values_low);
     let lowest = _mm_min_ps(highpack, lowpack);
@@ -49,62 +104,215 @@ unsafe fn core_argmin(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
     let lowpack = _mm_unpacklo_ps(lowest, lowest);
     let lowest = _mm_min_ps(highpack, lowpack);
 
-    let low_mask = _mm_cmpeq_ps(lowest, values_low);
+    let low_mask = _mm_castps_si128(_mm_cmpeq_ps(lowest, values_low));
 
-    index_low = _mm_or_ps(
-        _mm_and_ps(index_low, low_mask),
-        _mm_andnot_ps(low_mask, _mm_set1_ps(std::f32::MAX)),
+    let index_low = _mm_or_si128(
+        _mm_and_si128(index_low, low_mask),
+        _mm_andnot_si128(low_mask, _mm_set1_epi32(if LAST { -1 } else { i32::MAX })),
     );
 
     let value_array = std::mem::transmute::<__m128, [f32; 4]>(values_low);
-    let index_array = std::mem::transmute::<__m128, [f32; 4]>(index_low);
+    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_avx2<const LAST: bool>(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = _mm256_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm256_add_epi32(_mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0), offset);
+
+    let increment = _mm256_set1_epi32(8);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm256_loadu_ps(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_low = _mm256_add_epi32(new_index_low, increment);
+
+        let new_values = _mm256_loadu_ps(step.as_ptr());
+        let lt_mask = if LAST {
+            _mm256_cmp_ps::<_CMP_LE_OQ>(new_values, values_low)
+        } else {
+            _mm256_cmp_ps::<_CMP_LT_OQ>(new_values, values_low)
+        };
+
+        values_low = _mm256_blendv_ps(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, _mm256_castps_si256(lt_mask));
+    });
+
+    horizontal_argmin_avx2::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmin_avx2<const LAST: bool>(
+    values_low: __m256,
+    index_low: __m256i,
+) -> (f32, usize) {
+    let swapped = _mm256_permute2f128_ps::<0x01>(values_low, values_low);
+    let lowest = _mm256_min_ps(values_low, swapped);
+
+    let swapped = _mm256_permute_ps::<0b01_00_11_10>(lowest);
+    let lowest = _mm256_min_ps(lowest, swapped);
+
+    let swapped = _mm256_permute_ps::<0b10_11_00_01>(lowest);
+    let lowest = _mm256_min_ps(lowest, swapped);
+
+    let low_mask = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_EQ_OQ>(lowest, values_low));
 
-    let min_index = simple_argmin(&index_array);
+    let index_low = _mm256_blendv_epi8(
+        _mm256_set1_epi32(if LAST { -1 } else { i32::MAX }),
+        index_low,
+        low_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256, [f32; 8]>(values_low);
+    let index_array = std::mem::transmute::<__m256i, [i32; 8]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
     let value = *value_array.get_unchecked(min_index);
     let index = *index_array.get_unchecked(min_index);
 
     (value, index as usize)
 }
 
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmin_avx512<const LAST: bool>(arr: &[f32]) -> (f32, usize) {
+    let mut index_low = _mm512_setzero_si512();
+    let mut new_index_low = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi32(16);
+
+    let mut values_low = _mm512_set1_ps(arr[0]);
+
+    arr.chunks(16).for_each(|step| {
+        let mask = (u32::MAX >> (32 - step.len())) as __mmask16;
+
+        let new_values = _mm512_maskz_loadu_ps(mask, step.as_ptr());
+        let lt_mask = if LAST {
+            _mm512_mask_cmp_ps_mask::<_CMP_LE_OQ>(mask, new_values, values_low)
+        } else {
+            _mm512_mask_cmp_ps_mask::<_CMP_LT_OQ>(mask, new_values, values_low)
+        };
+
+        values_low = _mm512_mask_blend_ps(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi32(lt_mask, index_low, new_index_low);
+
+        new_index_low = _mm512_add_epi32(new_index_low, increment);
+    });
+
+    horizontal_argmin_avx512::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmin_avx512<const LAST: bool>(
+    values_low: __m512,
+    index_low: __m512i,
+) -> (f32, usize) {
+    let value = _mm512_reduce_min_ps(values_low);
+    let low_mask = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(values_low, _mm512_set1_ps(value));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi32(low_mask, index_low)
+    } else {
+        _mm512_mask_reduce_min_epi32(low_mask, index_low)
+    };
+
+    (value, index as usize)
+}
+
 pub fn argmax_f32(arr: &[f32]) -> Option<usize> {
-    match split_array(arr, 4) {
+    max_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmax_f32`], but the last index is returned among equal maxima.
+pub fn argmax_f32_last(arr: &[f32]) -> Option<usize> {
+    max_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_f32(arr: &[f32]) -> Option<(f32, usize)> {
+    max_with_ties::<false>(arr)
+}
+
+fn max_with_ties<const LAST: bool>(arr: &[f32]) -> Option<(f32, usize)> {
+    if simd_level() == SimdLevel::Avx512 {
+        return (!arr.is_empty()).then(|| {
+            let kernel = |block: &[f32], _: usize| unsafe { core_argmax_avx512::<LAST>(block) };
+            unsafe { blocked_argmax::<_, LAST>(arr, 0, I32_BLOCK_SIZE, kernel) }
+        });
+    }
+
+    let mut simd_func: unsafe fn(&[f32], usize) -> (f32, usize) = core_argmax::<LAST>;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_avx2::<LAST>;
+        mod_size = 8;
+    };
+
+    match split_array(arr, mod_size) {
         (Some(rem), Some(sim)) => {
-            let rem_min_index = simple_argmax(rem);
+            let rem_min_index = simple_argmax_tie::<_, LAST>(rem);
             let rem_result = (rem[rem_min_index], rem_min_index);
-            let sim_result = unsafe { core_argmax(sim, rem.len()) };
-            find_final_index_max(rem_result, sim_result)
+            let sim_result =
+                unsafe { blocked_argmax::<_, LAST>(sim, rem.len(), I32_BLOCK_SIZE, simd_func) };
+            Some(merge_max_tie::<_, LAST>(rem_result, sim_result))
         }
-        (Some(rem), None) => Some(simple_argmax(rem)),
+        (Some(rem), None) => Some(with_value(rem, simple_argmax_tie::<_, LAST>(rem))),
         (None, Some(sim)) => {
-            let sim_result = unsafe { core_argmax(sim, 0) };
-            Some(sim_result.1)
+            let sim_result =
+                unsafe { blocked_argmax::<_, LAST>(sim, 0, I32_BLOCK_SIZE, simd_func) };
+            Some(sim_result)
         }
         (None, None) => None,
     }
 }
 
-unsafe fn core_argmax(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
-    let offset = _mm_set1_ps(rem_offset as f32);
-    let mut index_high = _mm_add_ps(_mm_set_ps(3.0, 2.0, 1.0, 0.0), offset);
+unsafe fn core_argmax<const LAST: bool>(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_high = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
     let mut new_index_high = index_high;
 
-    let increment = _mm_set1_ps(4.0);
+    let increment = _mm_set1_epi32(4);
 
-    let mut values_high = _mm_loadu_ps(sim_arr.as_ptr() as *const f32);
+    let mut values_high = _mm_loadu_ps(sim_arr.as_ptr());
 
     sim_arr.chunks_exact(4).skip(1).for_each(|step| {
-        new_index_high = _mm_add_ps(new_index_high, increment);
+        new_index_high = _mm_add_epi32(new_index_high, increment);
 
-        let new_values = _mm_loadu_ps(step.as_ptr() as *const f32);
-        let gt_mask = _mm_cmpgt_ps(new_values, values_high);
+        let new_values = _mm_loadu_ps(step.as_ptr());
+        let gt_mask = if LAST {
+            _mm_castps_si128(_mm_cmpge_ps(new_values, values_high))
+        } else {
+            _mm_castps_si128(_mm_cmpgt_ps(new_values, values_high))
+        };
 
         values_high = _mm_max_ps(new_values, values_high);
-        index_high = _mm_or_ps(
-            _mm_and_ps(new_index_high, gt_mask),
-            _mm_andnot_ps(gt_mask, index_high),
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index_high, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
         );
     });
 
+    horizontal_argmax::<LAST>(values_high, index_high)
+}
+
+unsafe fn horizontal_argmax<const LAST: bool>(
+    values_high: __m128,
+    index_high: __m128i,
+) -> (f32, usize) {
     let highpack = _mm_unpackhi_ps(values_high, values_high);
     let lowpack = _mm_unpacklo_ps(values_high, values_high);
     let highest = _mm_max_ps(highpack, lowpack);
@@ -113,32 +321,932 @@ unsafe fn core_argmax(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
     let lowpack = _mm_unpacklo_ps(highest, highest);
     let highest = _mm_max_ps(highpack, lowpack);
 
-    let high_mask = _mm_cmpeq_ps(highest, values_high);
+    let high_mask = _mm_castps_si128(_mm_cmpeq_ps(highest, values_high));
 
-    index_high = _mm_or_ps(
-        _mm_and_ps(index_high, high_mask),
-        _mm_andnot_ps(high_mask, _mm_set1_ps(std::f32::MAX)),
+    let index_high = _mm_or_si128(
+        _mm_and_si128(index_high, high_mask),
+        _mm_andnot_si128(high_mask, _mm_set1_epi32(if LAST { -1 } else { i32::MAX })),
     );
 
     let value_array = std::mem::transmute::<__m128, [f32; 4]>(values_high);
-    let index_array = std::mem::transmute::<__m128, [f32; 4]>(index_high);
+    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_high);
 
-    let max_index = simple_argmin(&index_array);
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
     let value = *value_array.get_unchecked(max_index);
     let index = *index_array.get_unchecked(max_index);
 
     (value, index as usize)
 }
 
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_avx2<const LAST: bool>(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = _mm256_set1_epi32(rem_offset as i32);
+    let mut index_high = _mm256_add_epi32(_mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm256_set1_epi32(8);
+
+    let mut values_high = _mm256_loadu_ps(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_high = _mm256_add_epi32(new_index_high, increment);
+
+        let new_values = _mm256_loadu_ps(step.as_ptr());
+        let gt_mask = if LAST {
+            _mm256_cmp_ps::<_CMP_GE_OQ>(new_values, values_high)
+        } else {
+            _mm256_cmp_ps::<_CMP_GT_OQ>(new_values, values_high)
+        };
+
+        values_high = _mm256_blendv_ps(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, _mm256_castps_si256(gt_mask));
+    });
+
+    horizontal_argmax_avx2::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmax_avx2<const LAST: bool>(
+    values_high: __m256,
+    index_high: __m256i,
+) -> (f32, usize) {
+    let swapped = _mm256_permute2f128_ps::<0x01>(values_high, values_high);
+    let highest = _mm256_max_ps(values_high, swapped);
+
+    let swapped = _mm256_permute_ps::<0b01_00_11_10>(highest);
+    let highest = _mm256_max_ps(highest, swapped);
+
+    let swapped = _mm256_permute_ps::<0b10_11_00_01>(highest);
+    let highest = _mm256_max_ps(highest, swapped);
+
+    let high_mask = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_EQ_OQ>(highest, values_high));
+
+    let index_high = _mm256_blendv_epi8(
+        _mm256_set1_epi32(if LAST { -1 } else { i32::MAX }),
+        index_high,
+        high_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256, [f32; 8]>(values_high);
+    let index_array = std::mem::transmute::<__m256i, [i32; 8]>(index_high);
+
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(max_index);
+    let index = *index_array.get_unchecked(max_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmax_avx512<const LAST: bool>(arr: &[f32]) -> (f32, usize) {
+    let mut index_high = _mm512_setzero_si512();
+    let mut new_index_high = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi32(16);
+
+    let mut values_high = _mm512_set1_ps(arr[0]);
+
+    arr.chunks(16).for_each(|step| {
+        let mask = (u32::MAX >> (32 - step.len())) as __mmask16;
+
+        let new_values = _mm512_maskz_loadu_ps(mask, step.as_ptr());
+        let gt_mask = if LAST {
+            _mm512_mask_cmp_ps_mask::<_CMP_GE_OQ>(mask, new_values, values_high)
+        } else {
+            _mm512_mask_cmp_ps_mask::<_CMP_GT_OQ>(mask, new_values, values_high)
+        };
+
+        values_high = _mm512_mask_blend_ps(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi32(gt_mask, index_high, new_index_high);
+
+        new_index_high = _mm512_add_epi32(new_index_high, increment);
+    });
+
+    horizontal_argmax_avx512::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmax_avx512<const LAST: bool>(
+    values_high: __m512,
+    index_high: __m512i,
+) -> (f32, usize) {
+    let value = _mm512_reduce_max_ps(values_high);
+    let high_mask = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(values_high, _mm512_set1_ps(value));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi32(high_mask, index_high)
+    } else {
+        _mm512_mask_reduce_min_epi32(high_mask, index_high)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argminmax_f32(arr: &[f32]) -> Option<(usize, usize)> {
+    minmax_with_indices_f32(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_f32(arr: &[f32]) -> Option<MinMaxResult<f32>> {
+    if simd_level() == SimdLevel::Avx512 {
+        return (!arr.is_empty()).then(|| unsafe {
+            blocked_argminmax(arr, 0, I32_BLOCK_SIZE, |block, _| {
+                core_argminmax_avx512(block)
+            })
+        });
+    }
+
+    let mut simd_func: unsafe fn(&[f32], usize) -> MinMaxResult<f32> = core_argminmax;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argminmax_avx2;
+        mod_size = 8;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) =
+                unsafe { blocked_argminmax(sim, rem.len(), I32_BLOCK_SIZE, simd_func) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => {
+            let (sim_min_result, sim_max_result) =
+                unsafe { blocked_argminmax(sim, 0, I32_BLOCK_SIZE, simd_func) };
+            Some((sim_min_result, sim_max_result))
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argminmax(sim_arr: &[f32], rem_offset: usize) -> MinMaxResult<f32> {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm_set1_epi32(4);
+
+    let mut values_low = _mm_loadu_ps(sim_arr.as_ptr());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index = _mm_add_epi32(new_index, increment);
+
+        let new_values = _mm_loadu_ps(step.as_ptr());
+        let lt_mask = _mm_castps_si128(_mm_cmplt_ps(new_values, values_low));
+        let gt_mask = _mm_castps_si128(_mm_cmpgt_ps(new_values, values_high));
+
+        values_low = _mm_min_ps(new_values, values_low);
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
+        );
+
+        values_high = _mm_max_ps(new_values, values_high);
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
+        );
+    });
+
+    (
+        horizontal_argmin::<false>(values_low, index_low),
+        horizontal_argmax::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argminmax_avx2(sim_arr: &[f32], rem_offset: usize) -> MinMaxResult<f32> {
+    let offset = _mm256_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm256_add_epi32(_mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm256_set1_epi32(8);
+
+    let mut values_low = _mm256_loadu_ps(sim_arr.as_ptr());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index = _mm256_add_epi32(new_index, increment);
+
+        let new_values = _mm256_loadu_ps(step.as_ptr());
+        let lt_mask = _mm256_cmp_ps::<_CMP_LT_OQ>(new_values, values_low);
+        let gt_mask = _mm256_cmp_ps::<_CMP_GT_OQ>(new_values, values_high);
+
+        values_low = _mm256_blendv_ps(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index, _mm256_castps_si256(lt_mask));
+
+        values_high = _mm256_blendv_ps(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index, _mm256_castps_si256(gt_mask));
+    });
+
+    (
+        horizontal_argmin_avx2::<false>(values_low, index_low),
+        horizontal_argmax_avx2::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argminmax_avx512(arr: &[f32]) -> MinMaxResult<f32> {
+    let mut index_low = _mm512_setzero_si512();
+    let mut index_high = index_low;
+    let mut new_index = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi32(16);
+
+    let mut values_low = _mm512_set1_ps(arr[0]);
+    let mut values_high = values_low;
+
+    arr.chunks(16).for_each(|step| {
+        let mask = (u32::MAX >> (32 - step.len())) as __mmask16;
+
+        let new_values = _mm512_maskz_loadu_ps(mask, step.as_ptr());
+        let lt_mask = _mm512_mask_cmp_ps_mask::<_CMP_LT_OQ>(mask, new_values, values_low);
+        let gt_mask = _mm512_mask_cmp_ps_mask::<_CMP_GT_OQ>(mask, new_values, values_high);
+
+        values_low = _mm512_mask_blend_ps(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi32(lt_mask, index_low, new_index);
+
+        values_high = _mm512_mask_blend_ps(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi32(gt_mask, index_high, new_index);
+
+        new_index = _mm512_add_epi32(new_index, increment);
+    });
+
+    (
+        horizontal_argmin_avx512::<false>(values_low, index_low),
+        horizontal_argmax_avx512::<false>(values_high, index_high),
+    )
+}
+
+pub fn nanargmin_f32(arr: &[f32]) -> Option<usize> {
+    match split_array(arr, 4) {
+        (Some(rem), Some(sim)) => {
+            let sim_result = unsafe {
+                blocked_argmin::<_, false>(sim, rem.len(), I32_BLOCK_SIZE, core_nanargmin)
+            };
+            if sim_result.0 == f32::INFINITY {
+                return simple_nanargmin(arr);
+            }
+            match simple_nanargmin(rem) {
+                Some(rem_min_index) => {
+                    let rem_result = (rem[rem_min_index], rem_min_index);
+                    find_final_index_min(rem_result, sim_result)
+                }
+                None => Some(sim_result.1),
+            }
+        }
+        (Some(rem), None) => simple_nanargmin(rem),
+        (None, Some(sim)) => {
+            let sim_result =
+                unsafe { blocked_argmin::<_, false>(sim, 0, I32_BLOCK_SIZE, core_nanargmin) };
+            if sim_result.0 == f32::INFINITY {
+                return simple_nanargmin(sim);
+            }
+            Some(sim_result.1)
+        }
+        (None, None) => None,
+    }
+}
+
+/// NaN lanes are replaced with +inf, so a winning value of +inf is ambiguous
+/// and the caller falls back to a scalar scan.
+unsafe fn core_nanargmin(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+
+    let increment = _mm_set1_epi32(4);
+    let mut new_index_low = index_low;
+
+    let replacement = _mm_set1_ps(f32::INFINITY);
+    let mut values_low = replace_nan(_mm_loadu_ps(sim_arr.as_ptr()), replacement);
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_low = _mm_add_epi32(new_index_low, increment);
+
+        let new_values = replace_nan(_mm_loadu_ps(step.as_ptr()), replacement);
+        let lt_mask = _mm_castps_si128(_mm_cmplt_ps(new_values, values_low));
+
+        values_low = _mm_min_ps(new_values, values_low);
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index_low, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
+        );
+    });
+
+    horizontal_argmin::<false>(values_low, index_low)
+}
+
+pub fn nanargmax_f32(arr: &[f32]) -> Option<usize> {
+    match split_array(arr, 4) {
+        (Some(rem), Some(sim)) => {
+            let sim_result = unsafe {
+                blocked_argmax::<_, false>(sim, rem.len(), I32_BLOCK_SIZE, core_nanargmax)
+            };
+            if sim_result.0 == f32::NEG_INFINITY {
+                return simple_nanargmax(arr);
+            }
+            match simple_nanargmax(rem) {
+                Some(rem_max_index) => {
+                    let rem_result = (rem[rem_max_index], rem_max_index);
+                    find_final_index_max(rem_result, sim_result)
+                }
+                None => Some(sim_result.1),
+            }
+        }
+        (Some(rem), None) => simple_nanargmax(rem),
+        (None, Some(sim)) => {
+            let sim_result =
+                unsafe { blocked_argmax::<_, false>(sim, 0, I32_BLOCK_SIZE, core_nanargmax) };
+            if sim_result.0 == f32::NEG_INFINITY {
+                return simple_nanargmax(sim);
+            }
+            Some(sim_result.1)
+        }
+        (None, None) => None,
+    }
+}
+
+/// NaN lanes are replaced with -inf, so a winning value of -inf is ambiguous
+/// and the caller falls back to a scalar scan.
+unsafe fn core_nanargmax(sim_arr: &[f32], rem_offset: usize) -> (f32, usize) {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_high = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm_set1_epi32(4);
+
+    let replacement = _mm_set1_ps(f32::NEG_INFINITY);
+    let mut values_high = replace_nan(_mm_loadu_ps(sim_arr.as_ptr()), replacement);
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_high = _mm_add_epi32(new_index_high, increment);
+
+        let new_values = replace_nan(_mm_loadu_ps(step.as_ptr()), replacement);
+        let gt_mask = _mm_castps_si128(_mm_cmpgt_ps(new_values, values_high));
+
+        values_high = _mm_max_ps(new_values, values_high);
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index_high, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
+        );
+    });
+
+    horizontal_argmax::<false>(values_high, index_high)
+}
+
+unsafe fn replace_nan(values: __m128, replacement: __m128) -> __m128 {
+    let nan_mask = _mm_cmpunord_ps(values, values);
+    _mm_or_ps(
+        _mm_and_ps(replacement, nan_mask),
+        _mm_andnot_ps(nan_mask, values),
+    )
+}
+
+pub fn first_nan_f32(arr: &[f32]) -> Option<usize> {
+    unsafe { core_first_nan(arr) }
+}
+
+unsafe fn core_first_nan(arr: &[f32]) -> Option<usize> {
+    let chunks = arr.chunks_exact(4);
+    let tail = chunks.remainder();
+
+    for (i, step) in chunks.enumerate() {
+        let values = _mm_loadu_ps(step.as_ptr());
+        let nan_mask = _mm_movemask_ps(_mm_cmpunord_ps(values, values));
+        if nan_mask != 0 {
+            return Some(i * 4 + nan_mask.trailing_zeros() as usize);
+        }
+    }
+
+    simple_first_nan(tail).map(|index| arr.len() - tail.len() + index)
+}
+
+/// Argmin of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ... counted in strided
+/// elements. Strides above 4 are read with AVX2 gathers, the others are copied
+/// out a block at a time and handed to [`argmin_f32`].
+pub fn argmin_f32_strided(arr: &[f32], stride: usize) -> Option<usize> {
+    assert!(stride > 0, "stride must be non-zero");
+
+    if stride <= 4 || stride > MAX_GATHER_STRIDE || simd_level() < SimdLevel::Avx2 {
+        return deinterleaved_argmin(arr, stride, argmin_f32);
+    }
+
+    match split_gathers(arr, stride) {
+        (0 | 1, _) => deinterleaved_argmin(arr, stride, argmin_f32),
+        (gathers, rem) => {
+            let sim_result = unsafe {
+                blocked_gathers(
+                    arr,
+                    stride,
+                    gathers,
+                    I32_BLOCK_SIZE,
+                    core_argmin_gather_avx2,
+                    merge_min,
+                )
+            };
+            if rem.is_empty() {
+                return Some(sim_result.1);
+            }
+            let rem_min_index = simple_argmin_strided(rem, stride);
+            let rem_result = (rem[rem_min_index * stride], gathers * 8 + rem_min_index);
+            find_final_index_min(rem_result, sim_result)
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_gather_avx2(arr: &[f32], stride: usize, gathers: usize) -> (f32, usize) {
+    let offsets = _mm256_mullo_epi32(
+        _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0),
+        _mm256_set1_epi32(stride as i32),
+    );
+    let mut index_low = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm256_set1_epi32(8);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm256_i32gather_ps::<4>(arr.as_ptr(), offsets);
+
+    (1..gathers).for_each(|gather| {
+        new_index_low = _mm256_add_epi32(new_index_low, increment);
+
+        let step = arr.as_ptr().add(gather * 8 * stride);
+        let new_values = _mm256_i32gather_ps::<4>(step, offsets);
+        let lt_mask = _mm256_cmp_ps::<_CMP_LT_OQ>(new_values, values_low);
+
+        values_low = _mm256_blendv_ps(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, _mm256_castps_si256(lt_mask));
+    });
+
+    horizontal_argmin_avx2::<false>(values_low, index_low)
+}
+
+/// Argmax of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ... counted in strided
+/// elements, see [`argmin_f32_strided`].
+pub fn argmax_f32_strided(arr: &[f32], stride: usize) -> Option<usize> {
+    assert!(stride > 0, "stride must be non-zero");
+
+    if stride <= 4 || stride > MAX_GATHER_STRIDE || simd_level() < SimdLevel::Avx2 {
+        return deinterleaved_argmax(arr, stride, argmax_f32);
+    }
+
+    match split_gathers(arr, stride) {
+        (0 | 1, _) => deinterleaved_argmax(arr, stride, argmax_f32),
+        (gathers, rem) => {
+            let sim_result = unsafe {
+                blocked_gathers(
+                    arr,
+                    stride,
+                    gathers,
+                    I32_BLOCK_SIZE,
+                    core_argmax_gather_avx2,
+                    merge_max,
+                )
+            };
+            if rem.is_empty() {
+                return Some(sim_result.1);
+            }
+            let rem_max_index = simple_argmax_strided(rem, stride);
+            let rem_result = (rem[rem_max_index * stride], gathers * 8 + rem_max_index);
+            find_final_index_max(rem_result, sim_result)
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_gather_avx2(arr: &[f32], stride: usize, gathers: usize) -> (f32, usize) {
+    let offsets = _mm256_mullo_epi32(
+        _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0),
+        _mm256_set1_epi32(stride as i32),
+    );
+    let mut index_high = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm256_set1_epi32(8);
+    let mut new_index_high = index_high;
+
+    let mut values_high = _mm256_i32gather_ps::<4>(arr.as_ptr(), offsets);
+
+    (1..gathers).for_each(|gather| {
+        new_index_high = _mm256_add_epi32(new_index_high, increment);
+
+        let step = arr.as_ptr().add(gather * 8 * stride);
+        let new_values = _mm256_i32gather_ps::<4>(step, offsets);
+        let gt_mask = _mm256_cmp_ps::<_CMP_GT_OQ>(new_values, values_high);
+
+        values_high = _mm256_blendv_ps(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, _mm256_castps_si256(gt_mask));
+    });
+
+    horizontal_argmax_avx2::<false>(values_high, index_high)
+}
+
+/// Row of the smallest value in every column of a row-major matrix with `cols`
+/// columns. Whole rows are compared lane by lane against the running minima.
+pub(crate) fn argmin_f32_columns(data: &[f32], cols: usize) -> Vec<usize> {
+    assert!(data.len() / cols <= i32::MAX as usize);
+
+    let mut values_low = data[..cols].to_vec();
+    let mut index_low = vec![0i32; cols];
+
+    if simd_level() >= SimdLevel::Avx2 {
+        unsafe { core_argmin_columns_avx2(data, &mut values_low, &mut index_low) };
+    } else {
+        unsafe { core_argmin_columns(data, &mut values_low, &mut index_low) };
+    }
+
+    index_low.into_iter().map(|index| index as usize).collect()
+}
+
+unsafe fn core_argmin_columns(data: &[f32], values_low: &mut [f32], index_low: &mut [i32]) {
+    let cols = values_low.len();
+    let body = cols - cols % 4;
+
+    for (row_index, row) in data.chunks_exact(cols).enumerate().skip(1) {
+        let new_index = _mm_set1_epi32(row_index as i32);
+
+        row.chunks_exact(4)
+            .zip(values_low.chunks_exact_mut(4))
+            .zip(index_low.chunks_exact_mut(4))
+            .for_each(|((step, values), indices)| {
+                let new_values = _mm_loadu_ps(step.as_ptr());
+                let old_values = _mm_loadu_ps(values.as_ptr());
+                let old_index = _mm_loadu_si128(indices.as_ptr() as *const __m128i);
+
+                let lt_mask = _mm_cmplt_ps(new_values, old_values);
+                let lt_index_mask = _mm_castps_si128(lt_mask);
+
+                let new_values = _mm_or_ps(
+                    _mm_and_ps(new_values, lt_mask),
+                    _mm_andnot_ps(lt_mask, old_values),
+                );
+                let new_index = _mm_or_si128(
+                    _mm_and_si128(new_index, lt_index_mask),
+                    _mm_andnot_si128(lt_index_mask, old_index),
+                );
+
+                _mm_storeu_ps(values.as_mut_ptr(), new_values);
+                _mm_storeu_si128(indices.as_mut_ptr() as *mut __m128i, new_index);
+            });
+
+        for column in body..cols {
+            if row[column] < values_low[column] {
+                values_low[column] = row[column];
+                index_low[column] = row_index as i32;
+            }
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_columns_avx2(data: &[f32], values_low: &mut [f32], index_low: &mut [i32]) {
+    let cols = values_low.len();
+    let body = cols - cols % 8;
+
+    for (row_index, row) in data.chunks_exact(cols).enumerate().skip(1) {
+        let new_index = _mm256_set1_epi32(row_index as i32);
+
+        row.chunks_exact(8)
+            .zip(values_low.chunks_exact_mut(8))
+            .zip(index_low.chunks_exact_mut(8))
+            .for_each(|((step, values), indices)| {
+                let new_values = _mm256_loadu_ps(step.as_ptr());
+                let old_values = _mm256_loadu_ps(values.as_ptr());
+                let old_index = _mm256_loadu_si256(indices.as_ptr() as *const __m256i);
+
+                let lt_mask = _mm256_cmp_ps::<_CMP_LT_OQ>(new_values, old_values);
+
+                let new_values = _mm256_blendv_ps(old_values, new_values, lt_mask);
+                let new_index =
+                    _mm256_blendv_epi8(old_index, new_index, _mm256_castps_si256(lt_mask));
+
+                _mm256_storeu_ps(values.as_mut_ptr(), new_values);
+                _mm256_storeu_si256(indices.as_mut_ptr() as *mut __m256i, new_index);
+            });
+
+        for column in body..cols {
+            if row[column] < values_low[column] {
+                values_low[column] = row[column];
+                index_low[column] = row_index as i32;
+            }
+        }
+    }
+}
+
+/// Row of the largest value in every column of a row-major matrix with `cols`
+/// columns. Whole rows are compared lane by lane against the running maxima.
+pub(crate) fn argmax_f32_columns(data: &[f32], cols: usize) -> Vec<usize> {
+    assert!(data.len() / cols <= i32::MAX as usize);
+
+    let mut values_high = data[..cols].to_vec();
+    let mut index_high = vec![0i32; cols];
+
+    if simd_level() >= SimdLevel::Avx2 {
+        unsafe { core_argmax_columns_avx2(data, &mut values_high, &mut index_high) };
+    } else {
+        unsafe { core_argmax_columns(data, &mut values_high, &mut index_high) };
+    }
+
+    index_high.into_iter().map(|index| index as usize).collect()
+}
+
+unsafe fn core_argmax_columns(data: &[f32], values_high: &mut [f32], index_high: &mut [i32]) {
+    let cols = values_high.len();
+    let body = cols - cols % 4;
+
+    for (row_index, row) in data.chunks_exact(cols).enumerate().skip(1) {
+        let new_index = _mm_set1_epi32(row_index as i32);
+
+        row.chunks_exact(4)
+            .zip(values_high.chunks_exact_mut(4))
+            .zip(index_high.chunks_exact_mut(4))
+            .for_each(|((step, values), indices)| {
+                let new_values = _mm_loadu_ps(step.as_ptr());
+                let old_values = _mm_loadu_ps(values.as_ptr());
+                let old_index = _mm_loadu_si128(indices.as_ptr() as *const __m128i);
+
+                let gt_mask = _mm_cmpgt_ps(new_values, old_values);
+                let gt_index_mask = _mm_castps_si128(gt_mask);
+
+                let new_values = _mm_or_ps(
+                    _mm_and_ps(new_values, gt_mask),
+                    _mm_andnot_ps(gt_mask, old_values),
+                );
+                let new_index = _mm_or_si128(
+                    _mm_and_si128(new_index, gt_index_mask),
+                    _mm_andnot_si128(gt_index_mask, old_index),
+                );
+
+                _mm_storeu_ps(values.as_mut_ptr(), new_values);
+                _mm_storeu_si128(indices.as_mut_ptr() as *mut __m128i, new_index);
+            });
+
+        for column in body..cols {
+            if row[column] > values_high[column] {
+                values_high[column] = row[column];
+                index_high[column] = row_index as i32;
+            }
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_columns_avx2(data: &[f32], values_high: &mut [f32], index_high: &mut [i32]) {
+    let cols = values_high.len();
+    let body = cols - cols % 8;
+
+    for (row_index, row) in data.chunks_exact(cols).enumerate().skip(1) {
+        let new_index = _mm256_set1_epi32(row_index as i32);
+
+        row.chunks_exact(8)
+            .zip(values_high.chunks_exact_mut(8))
+            .zip(index_high.chunks_exact_mut(8))
+            .for_each(|((step, values), indices)| {
+                let new_values = _mm256_loadu_ps(step.as_ptr());
+                let old_values = _mm256_loadu_ps(values.as_ptr());
+                let old_index = _mm256_loadu_si256(indices.as_ptr() as *const __m256i);
+
+                let gt_mask = _mm256_cmp_ps::<_CMP_GT_OQ>(new_values, old_values);
+
+                let new_values = _mm256_blendv_ps(old_values, new_values, gt_mask);
+                let new_index =
+                    _mm256_blendv_epi8(old_index, new_index, _mm256_castps_si256(gt_mask));
+
+                _mm256_storeu_ps(values.as_mut_ptr(), new_values);
+                _mm256_storeu_si256(indices.as_mut_ptr() as *mut __m256i, new_index);
+            });
+
+        for column in body..cols {
+            if row[column] > values_high[column] {
+                values_high[column] = row[column];
+                index_high[column] = row_index as i32;
+            }
+        }
+    }
+}
+
+/// Argmin of the elements of `arr` selected by `mask`, or `None` when nothing
+/// is selected. The selection becomes a lane mask that is ANDed into the
+/// compare, so the input is still scanned once with SIMD loads.
+pub fn argmin_f32_masked(arr: &[f32], mask: Mask) -> Option<usize> {
+    min_with_index_f32_masked(arr, mask).map(|(_, index)| index)
+}
+
+/// The smallest element selected by `mask` together with its first index.
+pub fn min_with_index_f32_masked(arr: &[f32], mask: Mask) -> Option<(f32, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return simple_min_with_index_masked(arr, mask);
+    }
+    mask.check_len(arr.len());
+
+    let mut simd_func: unsafe fn(&[f32], Mask, usize) -> MaskedResult<f32> = core_argmin_masked;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_masked_avx2;
+        mod_size = 8;
+    };
+
+    let (sim, rem) = arr.split_at(arr.len() - arr.len() % mod_size);
+    let sim_result = unsafe { blocked_masked(sim, mask, I32_BLOCK_SIZE, simd_func, merge_min) };
+    match (masked_argmin_from(rem, mask, sim.len()), sim_result) {
+        (Some(rem_result), Some(sim_result)) => Some(merge_min(rem_result, sim_result)),
+        (rem_result, sim_result) => rem_result.or(sim_result),
+    }
+}
+
+/// Lanes the mask leaves out keep their previous value and index. `seen` marks
+/// the lanes that have taken a selected value, and the first selected value of
+/// a lane is taken whatever it compares like. `offset` is the position of
+/// `sim_arr` in `mask`.
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmin_masked(sim_arr: &[f32], mask: Mask, offset: usize) -> MaskedResult<f32> {
+    let lane_bits = _mm_set_epi32(8, 4, 2, 1);
+    let mut new_index_low = _mm_set_epi32(3, 2, 1, 0);
+    let increment = _mm_set1_epi32(4);
+
+    let mut values_low = _mm_set1_ps(f32::INFINITY);
+    let mut index_low = _mm_set1_epi32(i32::MAX);
+    let mut seen = _mm_setzero_si128();
+
+    for (i, step) in sim_arr.chunks_exact(4).enumerate() {
+        let selected = lane_mask(mask.lanes(offset + i * 4, 4), lane_bits);
+
+        let new_values = _mm_loadu_ps(step.as_ptr());
+        let lt_mask = _mm_castps_si128(_mm_cmplt_ps(new_values, values_low));
+        let lt_mask = _mm_or_si128(
+            _mm_and_si128(lt_mask, selected),
+            _mm_andnot_si128(seen, selected),
+        );
+
+        values_low = _mm_blendv_ps(values_low, new_values, _mm_castsi128_ps(lt_mask));
+        index_low = _mm_blendv_epi8(index_low, new_index_low, lt_mask);
+        seen = _mm_or_si128(seen, selected);
+        new_index_low = _mm_add_epi32(new_index_low, increment);
+    }
+
+    (_mm_movemask_epi8(seen) != 0).then(|| horizontal_argmin::<false>(values_low, index_low))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_masked_avx2(sim_arr: &[f32], mask: Mask, offset: usize) -> MaskedResult<f32> {
+    let lane_bits = _mm256_set_epi32(128, 64, 32, 16, 8, 4, 2, 1);
+    let mut new_index_low = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+    let increment = _mm256_set1_epi32(8);
+
+    let mut values_low = _mm256_set1_ps(f32::INFINITY);
+    let mut index_low = _mm256_set1_epi32(i32::MAX);
+    let mut seen = _mm256_setzero_si256();
+
+    for (i, step) in sim_arr.chunks_exact(8).enumerate() {
+        let selected = lane_mask_avx2(mask.lanes(offset + i * 8, 8), lane_bits);
+
+        let new_values = _mm256_loadu_ps(step.as_ptr());
+        let lt_mask = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_LT_OQ>(new_values, values_low));
+        let lt_mask = _mm256_or_si256(
+            _mm256_and_si256(lt_mask, selected),
+            _mm256_andnot_si256(seen, selected),
+        );
+
+        values_low = _mm256_blendv_ps(values_low, new_values, _mm256_castsi256_ps(lt_mask));
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, lt_mask);
+        seen = _mm256_or_si256(seen, selected);
+        new_index_low = _mm256_add_epi32(new_index_low, increment);
+    }
+
+    (_mm256_movemask_epi8(seen) != 0)
+        .then(|| horizontal_argmin_avx2::<false>(values_low, index_low))
+}
+
+/// Argmax of the elements of `arr` selected by `mask`, see [`argmin_f32_masked`].
+pub fn argmax_f32_masked(arr: &[f32], mask: Mask) -> Option<usize> {
+    max_with_index_f32_masked(arr, mask).map(|(_, index)| index)
+}
+
+/// The largest element selected by `mask` together with its first index.
+pub fn max_with_index_f32_masked(arr: &[f32], mask: Mask) -> Option<(f32, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return simple_max_with_index_masked(arr, mask);
+    }
+    mask.check_len(arr.len());
+
+    let mut simd_func: unsafe fn(&[f32], Mask, usize) -> MaskedResult<f32> = core_argmax_masked;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_masked_avx2;
+        mod_size = 8;
+    };
+
+    let (sim, rem) = arr.split_at(arr.len() - arr.len() % mod_size);
+    let sim_result = unsafe { blocked_masked(sim, mask, I32_BLOCK_SIZE, simd_func, merge_max) };
+    match (masked_argmax_from(rem, mask, sim.len()), sim_result) {
+        (Some(rem_result), Some(sim_result)) => Some(merge_max(rem_result, sim_result)),
+        (rem_result, sim_result) => rem_result.or(sim_result),
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmax_masked(sim_arr: &[f32], mask: Mask, offset: usize) -> MaskedResult<f32> {
+    let lane_bits = _mm_set_epi32(8, 4, 2, 1);
+    let mut new_index_high = _mm_set_epi32(3, 2, 1, 0);
+    let increment = _mm_set1_epi32(4);
+
+    let mut values_high = _mm_set1_ps(f32::NEG_INFINITY);
+    let mut index_high = _mm_set1_epi32(i32::MAX);
+    let mut seen = _mm_setzero_si128();
+
+    for (i, step) in sim_arr.chunks_exact(4).enumerate() {
+        let selected = lane_mask(mask.lanes(offset + i * 4, 4), lane_bits);
+
+        let new_values = _mm_loadu_ps(step.as_ptr());
+        let gt_mask = _mm_castps_si128(_mm_cmpgt_ps(new_values, values_high));
+        let gt_mask = _mm_or_si128(
+            _mm_and_si128(gt_mask, selected),
+            _mm_andnot_si128(seen, selected),
+        );
+
+        values_high = _mm_blendv_ps(values_high, new_values, _mm_castsi128_ps(gt_mask));
+        index_high = _mm_blendv_epi8(index_high, new_index_high, gt_mask);
+        seen = _mm_or_si128(seen, selected);
+        new_index_high = _mm_add_epi32(new_index_high, increment);
+    }
+
+    (_mm_movemask_epi8(seen) != 0).then(|| horizontal_argmax::<false>(values_high, index_high))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_masked_avx2(sim_arr: &[f32], mask: Mask, offset: usize) -> MaskedResult<f32> {
+    let lane_bits = _mm256_set_epi32(128, 64, 32, 16, 8, 4, 2, 1);
+    let mut new_index_high = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+    let increment = _mm256_set1_epi32(8);
+
+    let mut values_high = _mm256_set1_ps(f32::NEG_INFINITY);
+    let mut index_high = _mm256_set1_epi32(i32::MAX);
+    let mut seen = _mm256_setzero_si256();
+
+    for (i, step) in sim_arr.chunks_exact(8).enumerate() {
+        let selected = lane_mask_avx2(mask.lanes(offset + i * 8, 8), lane_bits);
+
+        let new_values = _mm256_loadu_ps(step.as_ptr());
+        let gt_mask = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_GT_OQ>(new_values, values_high));
+        let gt_mask = _mm256_or_si256(
+            _mm256_and_si256(gt_mask, selected),
+            _mm256_andnot_si256(seen, selected),
+        );
+
+        values_high = _mm256_blendv_ps(values_high, new_values, _mm256_castsi256_ps(gt_mask));
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, gt_mask);
+        seen = _mm256_or_si256(seen, selected);
+        new_index_high = _mm256_add_epi32(new_index_high, increment);
+    }
+
+    (_mm256_movemask_epi8(seen) != 0)
+        .then(|| horizontal_argmax_avx2::<false>(values_high, index_high))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{argmax_f32, argmin_f32, simple_argmax, simple_argmin};
+    use super::{
+        argmax_f32, argmax_f32_columns, argmax_f32_last, argmax_f32_masked, argmax_f32_strided,
+        argmin_f32, argmin_f32_columns, argmin_f32_last, argmin_f32_masked, argmin_f32_strided,
+        argminmax_f32, core_argmax, core_argmax_avx2, core_argmax_avx512, core_argmax_columns,
+        core_argmax_columns_avx2, core_argmax_gather_avx2, core_argmax_masked,
+        core_argmax_masked_avx2, core_argmin, core_argmin_avx2, core_argmin_avx512,
+        core_argmin_columns, core_argmin_columns_avx2, core_argmin_gather_avx2, core_argmin_masked,
+        core_argmin_masked_avx2, core_argminmax, core_argminmax_avx2, core_argminmax_avx512,
+        core_nanargmax, core_nanargmin, first_nan_f32, nanargmax_f32, nanargmin_f32, simple_argmax,
+        simple_argmax_strided, simple_argmin, simple_argmin_strided, simple_first_nan,
+        simple_nanargmax, simple_nanargmin, split_gathers,
+    };
+    use crate::generic::{simple_argmax_columns, simple_argmin_columns};
+    use crate::generic::{simple_argmax_last, simple_argmin_last};
+    use crate::simd::{check_masked_kernels, MaskedKernel};
+    use crate::task::{
+        blocked_argmax, blocked_argmin, blocked_argminmax, blocked_gathers, merge_max, merge_min,
+    };
     use rand::{thread_rng, Rng};
     use rand_distr::Uniform;
 
     fn get_array_f32(n: usize) -> Vec<f32> {
         let rng = thread_rng();
-        let uni = Uniform::new_inclusive(std::f32::MIN, std::f32::MAX);
+        let uni = Uniform::new_inclusive(f32::MIN / 4.0, f32::MAX / 4.0);
         rng.sample_iter(uni).take(n).collect()
     }
 
@@ -156,15 +1264,271 @@ mod tests {
         assert_eq!(argmax_index, max_index);
     }
 
+    #[test]
+    fn test_sse_and_avx2_kernels_return_the_same_results() {
+        let data = get_array_f32(1024);
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+        assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, argmax_index);
+        let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+        assert_eq!((low, high), (argmin_index, argmax_index));
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(
+                unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx2::<false>(&data, 0) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_avx512_kernels_handle_masked_tail() {
+        if !(is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")) {
+            return;
+        }
+
+        for n in [1, 5, 8, 15, 16, 17, 1025] {
+            let data = get_array_f32(n);
+            let argmin_index = simple_argmin(&data);
+            let argmax_index = simple_argmax(&data);
+
+            assert_eq!(
+                unsafe { core_argmin_avx512::<false>(&data) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx512::<false>(&data) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_indices_are_exact_beyond_f32_mantissa() {
+        let n = (1 << 25) + 9;
+        let mut data = vec![0.0; n];
+        let argmin_index = n - 4;
+        let argmax_index = n - 2;
+        data[argmin_index] = -1.0;
+        data[argmax_index] = 1.0;
+
+        assert_eq!(argmin_f32(&data), Some(argmin_index));
+        assert_eq!(argmax_f32(&data), Some(argmax_index));
+        assert_eq!(argminmax_f32(&data), Some((argmin_index, argmax_index)));
+        assert_eq!(nanargmin_f32(&data), Some(argmin_index));
+        assert_eq!(nanargmax_f32(&data), Some(argmax_index));
+
+        let sim = &data[..n - 1];
+        assert_eq!(unsafe { core_argmin::<false>(sim, 0) }.1, argmin_index);
+        assert_eq!(unsafe { core_argmax::<false>(sim, 0) }.1, argmax_index);
+        assert_eq!(unsafe { core_nanargmin(sim, 0) }.1, argmin_index);
+        assert_eq!(unsafe { core_nanargmax(sim, 0) }.1, argmax_index);
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(unsafe { core_argmin_avx2::<false>(sim, 0) }.1, argmin_index);
+            assert_eq!(unsafe { core_argmax_avx2::<false>(sim, 0) }.1, argmax_index);
+        }
+
+        if is_x86_feature_detected!("avx512f") {
+            assert_eq!(
+                unsafe { core_argmin_avx512::<false>(&data) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx512::<false>(&data) }.1,
+                argmax_index
+            );
+        }
+    }
+
+    #[test]
+    fn test_blocked_kernels_rebase_indices_across_blocks() {
+        // Few distinct values, so the extremes tie across block boundaries.
+        let data: Vec<f32> = (0..328)
+            .map(|_| thread_rng().gen_range(0..4) as f32)
+            .collect();
+        let offset = 3;
+        let (min, max) = (simple_argmin(&data), simple_argmax(&data));
+        let (min_last, max_last) = (simple_argmin_last(&data), simple_argmax_last(&data));
+
+        type Kernel = unsafe fn(&[f32], usize) -> (f32, usize);
+        let mut kernels: Vec<[Kernel; 4]> = vec![[
+            core_argmin::<false>,
+            core_argmin::<true>,
+            core_argmax::<false>,
+            core_argmax::<true>,
+        ]];
+        if is_x86_feature_detected!("avx2") {
+            kernels.push([
+                core_argmin_avx2::<false>,
+                core_argmin_avx2::<true>,
+                core_argmax_avx2::<false>,
+                core_argmax_avx2::<true>,
+            ]);
+        }
+        if is_x86_feature_detected!("avx512f") {
+            kernels.push([
+                |block, _| unsafe { core_argmin_avx512::<false>(block) },
+                |block, _| unsafe { core_argmin_avx512::<true>(block) },
+                |block, _| unsafe { core_argmax_avx512::<false>(block) },
+                |block, _| unsafe { core_argmax_avx512::<true>(block) },
+            ]);
+        }
+
+        for [argmin, argmin_last, argmax, argmax_last] in kernels {
+            unsafe {
+                let result = blocked_argmin::<_, false>(&data, offset, 64, argmin);
+                assert_eq!(result.1, offset + min);
+                let result = blocked_argmin::<_, true>(&data, offset, 64, argmin_last);
+                assert_eq!(result.1, offset + min_last);
+                let result = blocked_argmax::<_, false>(&data, offset, 64, argmax);
+                assert_eq!(result.1, offset + max);
+                let result = blocked_argmax::<_, true>(&data, offset, 64, argmax_last);
+                assert_eq!(result.1, offset + max_last);
+            }
+        }
+
+        let ((_, low), (_, high)) = unsafe { blocked_argminmax(&data, offset, 64, core_argminmax) };
+        assert_eq!((low, high), (offset + min, offset + max));
+
+        let (_, low) = unsafe { blocked_argmin::<_, false>(&data, offset, 64, core_nanargmin) };
+        let (_, high) = unsafe { blocked_argmax::<_, false>(&data, offset, 64, core_nanargmax) };
+        assert_eq!((low, high), (offset + min, offset + max));
+    }
+
+    #[test]
+    fn test_column_kernels_match_scalar_columns() {
+        for cols in [1, 3, 4, 8, 13, 64] {
+            let data = get_array_f32(57 * cols);
+            let argmin_index = simple_argmin_columns(&data, cols);
+            let argmax_index = simple_argmax_columns(&data, cols);
+
+            assert_eq!(argmin_f32_columns(&data, cols), argmin_index);
+            assert_eq!(argmax_f32_columns(&data, cols), argmax_index);
+
+            let mut values = data[..cols].to_vec();
+            let mut index = vec![0; cols];
+            unsafe { core_argmin_columns(&data, &mut values, &mut index) };
+            assert!(index
+                .iter()
+                .map(|i| *i as usize)
+                .eq(argmin_index.iter().copied()));
+
+            let mut values = data[..cols].to_vec();
+            let mut index = vec![0; cols];
+            unsafe { core_argmax_columns(&data, &mut values, &mut index) };
+            assert!(index
+                .iter()
+                .map(|i| *i as usize)
+                .eq(argmax_index.iter().copied()));
+
+            if is_x86_feature_detected!("avx2") {
+                let mut values = data[..cols].to_vec();
+                let mut index = vec![0; cols];
+                unsafe { core_argmin_columns_avx2(&data, &mut values, &mut index) };
+                assert!(index
+                    .iter()
+                    .map(|i| *i as usize)
+                    .eq(argmin_index.iter().copied()));
+
+                let mut values = data[..cols].to_vec();
+                let mut index = vec![0; cols];
+                unsafe { core_argmax_columns_avx2(&data, &mut values, &mut index) };
+                assert!(index
+                    .iter()
+                    .map(|i| *i as usize)
+                    .eq(argmax_index.iter().copied()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_gather_kernels_match_scalar_strided() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        for stride in [5, 7, 16, 33] {
+            for n in [16 * stride, 16 * stride + 1, 1000 * stride - 3] {
+                let data = get_array_f32(n);
+                let (gathers, rem) = split_gathers(&data, stride);
+                let body = &data[..data.len() - rem.len()];
+
+                let (_, min_index) = unsafe { core_argmin_gather_avx2(body, stride, gathers) };
+                let (_, max_index) = unsafe { core_argmax_gather_avx2(body, stride, gathers) };
+                assert_eq!(min_index, simple_argmin_strided(body, stride));
+                assert_eq!(max_index, simple_argmax_strided(body, stride));
+
+                let (_, min_index) = unsafe {
+                    blocked_gathers(
+                        body,
+                        stride,
+                        gathers,
+                        16,
+                        core_argmin_gather_avx2,
+                        merge_min,
+                    )
+                };
+                let (_, max_index) = unsafe {
+                    blocked_gathers(
+                        body,
+                        stride,
+                        gathers,
+                        16,
+                        core_argmax_gather_avx2,
+                        merge_max,
+                    )
+                };
+                assert_eq!(min_index, simple_argmin_strided(body, stride));
+                assert_eq!(max_index, simple_argmax_strided(body, stride));
+
+                assert_eq!(
+                    argmin_f32_strided(&data, stride),
+                    Some(simple_argmin_strided(&data, stride))
+                );
+                assert_eq!(
+                    argmax_f32_strided(&data, stride),
+                    Some(simple_argmax_strided(&data, stride))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_masked_kernels_match_scalar_masked() {
+        let mut kernels: Vec<(usize, MaskedKernel<f32>, MaskedKernel<f32>)> =
+            vec![(4, core_argmin_masked, core_argmax_masked)];
+        if is_x86_feature_detected!("avx2") {
+            kernels.push((8, core_argmin_masked_avx2, core_argmax_masked_avx2));
+        }
+        check_masked_kernels(
+            get_array_f32,
+            |x| (x % 7.0).floor(),
+            &kernels,
+            argmin_f32_masked,
+            argmax_f32_masked,
+        );
+    }
+
     #[test]
     fn test_first_index_is_returned_when_identical_values_found() {
         let data = [
             10.,
-            std::f32::MAX,
+            f32::MAX,
             6.,
-            std::f32::NEG_INFINITY,
-            std::f32::NEG_INFINITY,
-            std::f32::MAX,
+            f32::NEG_INFINITY,
+            f32::NEG_INFINITY,
+            f32::MAX,
             10_000.0,
         ];
         let argmin_index = simple_argmin(&data);
@@ -177,4 +1541,96 @@ mod tests {
         assert_eq!(argmax_index, argmax_simd_index);
         assert_eq!(argmax_index, 1);
     }
+
+    #[test]
+    fn test_last_index_is_returned_when_identical_values_found() {
+        for n in [8, 9, 1025, 4099] {
+            let data: Vec<f32> = get_array_f32(n).iter().map(|x| (x % 7.0).floor()).collect();
+            assert_eq!(argmin_f32_last(&data), Some(simple_argmin_last(&data)));
+            assert_eq!(argmax_f32_last(&data), Some(simple_argmax_last(&data)));
+
+            let sim = &data[..n - n % 8];
+            let (min_index, max_index) = (simple_argmin_last(sim), simple_argmax_last(sim));
+            assert_eq!(unsafe { core_argmin::<true>(sim, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax::<true>(sim, 0) }.1, max_index);
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(unsafe { core_argmin_avx2::<true>(sim, 0) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx2::<true>(sim, 0) }.1, max_index);
+            }
+            if is_x86_feature_detected!("avx512f") {
+                assert_eq!(unsafe { core_argmin_avx512::<true>(sim) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx512::<true>(sim) }.1, max_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [7, 8, 9, 1025] {
+            let data = get_array_f32(n);
+            let (min_index, max_index) = argminmax_f32(&data).unwrap();
+            assert_eq!(min_index, simple_argmin(&data));
+            assert_eq!(max_index, simple_argmax(&data));
+        }
+    }
+
+    #[test]
+    fn test_nan_is_ignored_in_remainder_and_simd_body() {
+        let mut data = get_array_f32(1025);
+        assert_eq!(data.len() % 4, 1);
+
+        let min_index = simple_argmin(&data);
+        let max_index = simple_argmax(&data);
+        for i in [0, min_index, max_index, 10, 511, 1024] {
+            data[i] = f32::NAN;
+        }
+
+        assert_eq!(nanargmin_f32(&data), simple_nanargmin(&data));
+        assert_eq!(nanargmax_f32(&data), simple_nanargmax(&data));
+        assert_ne!(nanargmin_f32(&data), Some(min_index));
+        assert_ne!(nanargmax_f32(&data), Some(max_index));
+    }
+
+    #[test]
+    fn test_nan_is_ignored_when_extremum_is_infinite() {
+        let data = [
+            1.0,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NAN,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        assert_eq!(nanargmin_f32(&data[1..]), Some(1));
+        assert_eq!(nanargmax_f32(&data), Some(2));
+
+        let data = data.map(|v| -v);
+        assert_eq!(nanargmax_f32(&data[1..]), Some(1));
+        assert_eq!(nanargmin_f32(&data), Some(2));
+    }
+
+    #[test]
+    fn test_all_nan_returns_none() {
+        let data = [f32::NAN; 13];
+        assert_eq!(nanargmin_f32(&data), None);
+        assert_eq!(nanargmax_f32(&data), None);
+        assert_eq!(first_nan_f32(&data), Some(0));
+    }
+
+    #[test]
+    fn test_first_nan_is_found_in_remainder_and_simd_body() {
+        let mut data = get_array_f32(1027);
+        assert_eq!(first_nan_f32(&data), None);
+
+        data[1025] = f32::NAN;
+        assert_eq!(first_nan_f32(&data), Some(1025));
+
+        data[517] = f32::NAN;
+        data[903] = f32::NAN;
+        assert_eq!(first_nan_f32(&data), Some(517));
+        assert_eq!(first_nan_f32(&data), simple_first_nan(&data));
+    }
 }
@@ -0,0 +1,686 @@
+use crate::dispatch::{simd_level, SimdLevel};
+use crate::generic::{simple_argmax, simple_argmin, simple_argminmax};
+use crate::task::{
+    merge_max, merge_max_tie, merge_min, merge_min_tie, simple_argmax_tie, simple_argmin_tie,
+    split_array, with_value, with_values, MinMaxResult,
+};
+use std::arch::x86_64::*;
+
+pub fn argmin_f64(arr: &[f64]) -> Option<usize> {
+    min_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmin_f64`], but the last index is returned among equal minima.
+pub fn argmin_f64_last(arr: &[f64]) -> Option<usize> {
+    min_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_f64(arr: &[f64]) -> Option<(f64, usize)> {
+    min_with_ties::<false>(arr)
+}
+
+fn min_with_ties<const LAST: bool>(arr: &[f64]) -> Option<(f64, usize)> {
+    if simd_level() == SimdLevel::Avx512 {
+        return (!arr.is_empty()).then(|| unsafe { core_argmin_avx512::<LAST>(arr) });
+    }
+
+    let mut simd_func: unsafe fn(&[f64], usize) -> (f64, usize) = core_argmin::<LAST>;
+    let mut mod_size = 2;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_avx::<LAST>;
+        mod_size = 4;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin_tie::<_, LAST>(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result = unsafe { simd_func(sim, rem.len()) };
+            Some(merge_min_tie::<_, LAST>(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin_tie::<_, LAST>(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { simd_func(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argmin<const LAST: bool>(sim_arr: &[f64], rem_offset: usize) -> (f64, usize) {
+    let offset = _mm_set1_pd(rem_offset as f64);
+    let mut index_low = _mm_add_pd(_mm_set_pd(1.0, 0.0), offset);
+
+    let increment = _mm_set1_pd(2.0);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm_loadu_pd(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index_low = _mm_add_pd(new_index_low, increment);
+
+        let new_values = _mm_loadu_pd(step.as_ptr());
+        let lt_mask = if LAST {
+            _mm_cmple_pd(new_values, values_low)
+        } else {
+            _mm_cmplt_pd(new_values, values_low)
+        };
+
+        values_low = _mm_min_pd(new_values, values_low);
+        index_low = _mm_or_pd(
+            _mm_and_pd(new_index_low, lt_mask),
+            _mm_andnot_pd(lt_mask, index_low),
+        );
+    });
+
+    horizontal_argmin::<LAST>(values_low, index_low)
+}
+
+unsafe fn horizontal_argmin<const LAST: bool>(
+    values_low: __m128d,
+    index_low: __m128d,
+) -> (f64, usize) {
+    let highpack = _mm_unpackhi_pd(values_low, values_low);
+    let lowpack = _mm_unpacklo_pd(values_low, values_low);
+    let lowest = _mm_min_pd(highpack, lowpack);
+
+    let low_mask = _mm_cmpeq_pd(lowest, values_low);
+
+    let index_low = _mm_or_pd(
+        _mm_and_pd(index_low, low_mask),
+        _mm_andnot_pd(low_mask, _mm_set1_pd(if LAST { -1.0 } else { f64::MAX })),
+    );
+
+    let value_array = std::mem::transmute::<__m128d, [f64; 2]>(values_low);
+    let index_array = std::mem::transmute::<__m128d, [f64; 2]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx")]
+unsafe fn core_argmin_avx<const LAST: bool>(sim_arr: &[f64], rem_offset: usize) -> (f64, usize) {
+    let offset = _mm256_set1_pd(rem_offset as f64);
+    let mut index_low = _mm256_add_pd(_mm256_set_pd(3.0, 2.0, 1.0, 0.0), offset);
+
+    let increment = _mm256_set1_pd(4.0);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm256_loadu_pd(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_low = _mm256_add_pd(new_index_low, increment);
+
+        let new_values = _mm256_loadu_pd(step.as_ptr());
+        let lt_mask = if LAST {
+            _mm256_cmp_pd::<_CMP_LE_OQ>(new_values, values_low)
+        } else {
+            _mm256_cmp_pd::<_CMP_LT_OQ>(new_values, values_low)
+        };
+
+        values_low = _mm256_min_pd(new_values, values_low);
+        index_low = _mm256_blendv_pd(index_low, new_index_low, lt_mask);
+    });
+
+    horizontal_argmin_avx::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx")]
+unsafe fn horizontal_argmin_avx<const LAST: bool>(
+    values_low: __m256d,
+    index_low: __m256d,
+) -> (f64, usize) {
+    let swapped = _mm256_permute2f128_pd::<0x01>(values_low, values_low);
+    let lowest = _mm256_min_pd(values_low, swapped);
+
+    let swapped = _mm256_permute_pd::<0b0101>(lowest);
+    let lowest = _mm256_min_pd(lowest, swapped);
+
+    let low_mask = _mm256_cmp_pd::<_CMP_EQ_OQ>(lowest, values_low);
+
+    let index_low = _mm256_blendv_pd(
+        _mm256_set1_pd(if LAST { -1.0 } else { f64::MAX }),
+        index_low,
+        low_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256d, [f64; 4]>(values_low);
+    let index_array = std::mem::transmute::<__m256d, [f64; 4]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmin_avx512<const LAST: bool>(arr: &[f64]) -> (f64, usize) {
+    let mut index_low = _mm512_setzero_si512();
+    let mut new_index_low = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi64(8);
+
+    let mut values_low = _mm512_set1_pd(arr[0]);
+
+    arr.chunks(8).for_each(|step| {
+        let mask = (u16::MAX >> (16 - step.len())) as __mmask8;
+
+        let new_values = _mm512_maskz_loadu_pd(mask, step.as_ptr());
+        let lt_mask = if LAST {
+            _mm512_mask_cmp_pd_mask::<_CMP_LE_OQ>(mask, new_values, values_low)
+        } else {
+            _mm512_mask_cmp_pd_mask::<_CMP_LT_OQ>(mask, new_values, values_low)
+        };
+
+        values_low = _mm512_mask_blend_pd(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi64(lt_mask, index_low, new_index_low);
+
+        new_index_low = _mm512_add_epi64(new_index_low, increment);
+    });
+
+    horizontal_argmin_avx512::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmin_avx512<const LAST: bool>(
+    values_low: __m512d,
+    index_low: __m512i,
+) -> (f64, usize) {
+    let value = _mm512_reduce_min_pd(values_low);
+    let low_mask = _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(values_low, _mm512_set1_pd(value));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi64(low_mask, index_low)
+    } else {
+        _mm512_mask_reduce_min_epi64(low_mask, index_low)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argmax_f64(arr: &[f64]) -> Option<usize> {
+    max_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmax_f64`], but the last index is returned among equal maxima.
+pub fn argmax_f64_last(arr: &[f64]) -> Option<usize> {
+    max_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_f64(arr: &[f64]) -> Option<(f64, usize)> {
+    max_with_ties::<false>(arr)
+}
+
+fn max_with_ties<const LAST: bool>(arr: &[f64]) -> Option<(f64, usize)> {
+    if simd_level() == SimdLevel::Avx512 {
+        return (!arr.is_empty()).then(|| unsafe { core_argmax_avx512::<LAST>(arr) });
+    }
+
+    let mut simd_func: unsafe fn(&[f64], usize) -> (f64, usize) = core_argmax::<LAST>;
+    let mut mod_size = 2;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_avx::<LAST>;
+        mod_size = 4;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let rem_max_index = simple_argmax_tie::<_, LAST>(rem);
+            let rem_result = (rem[rem_max_index], rem_max_index);
+            let sim_result = unsafe { simd_func(sim, rem.len()) };
+            Some(merge_max_tie::<_, LAST>(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax_tie::<_, LAST>(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { simd_func(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argmax<const LAST: bool>(sim_arr: &[f64], rem_offset: usize) -> (f64, usize) {
+    let offset = _mm_set1_pd(rem_offset as f64);
+    let mut index_high = _mm_add_pd(_mm_set_pd(1.0, 0.0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm_set1_pd(2.0);
+
+    let mut values_high = _mm_loadu_pd(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index_high = _mm_add_pd(new_index_high, increment);
+
+        let new_values = _mm_loadu_pd(step.as_ptr());
+        let gt_mask = if LAST {
+            _mm_cmpge_pd(new_values, values_high)
+        } else {
+            _mm_cmpgt_pd(new_values, values_high)
+        };
+
+        values_high = _mm_max_pd(new_values, values_high);
+        index_high = _mm_or_pd(
+            _mm_and_pd(new_index_high, gt_mask),
+            _mm_andnot_pd(gt_mask, index_high),
+        );
+    });
+
+    horizontal_argmax::<LAST>(values_high, index_high)
+}
+
+unsafe fn horizontal_argmax<const LAST: bool>(
+    values_high: __m128d,
+    index_high: __m128d,
+) -> (f64, usize) {
+    let highpack = _mm_unpackhi_pd(values_high, values_high);
+    let lowpack = _mm_unpacklo_pd(values_high, values_high);
+    let highest = _mm_max_pd(highpack, lowpack);
+
+    let high_mask = _mm_cmpeq_pd(highest, values_high);
+
+    let index_high = _mm_or_pd(
+        _mm_and_pd(index_high, high_mask),
+        _mm_andnot_pd(high_mask, _mm_set1_pd(if LAST { -1.0 } else { f64::MAX })),
+    );
+
+    let value_array = std::mem::transmute::<__m128d, [f64; 2]>(values_high);
+    let index_array = std::mem::transmute::<__m128d, [f64; 2]>(index_high);
+
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(max_index);
+    let index = *index_array.get_unchecked(max_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx")]
+unsafe fn core_argmax_avx<const LAST: bool>(sim_arr: &[f64], rem_offset: usize) -> (f64, usize) {
+    let offset = _mm256_set1_pd(rem_offset as f64);
+    let mut index_high = _mm256_add_pd(_mm256_set_pd(3.0, 2.0, 1.0, 0.0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm256_set1_pd(4.0);
+
+    let mut values_high = _mm256_loadu_pd(sim_arr.as_ptr());
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_high = _mm256_add_pd(new_index_high, increment);
+
+        let new_values = _mm256_loadu_pd(step.as_ptr());
+        let gt_mask = if LAST {
+            _mm256_cmp_pd::<_CMP_GE_OQ>(new_values, values_high)
+        } else {
+            _mm256_cmp_pd::<_CMP_GT_OQ>(new_values, values_high)
+        };
+
+        values_high = _mm256_max_pd(new_values, values_high);
+        index_high = _mm256_blendv_pd(index_high, new_index_high, gt_mask);
+    });
+
+    horizontal_argmax_avx::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx")]
+unsafe fn horizontal_argmax_avx<const LAST: bool>(
+    values_high: __m256d,
+    index_high: __m256d,
+) -> (f64, usize) {
+    let swapped = _mm256_permute2f128_pd::<0x01>(values_high, values_high);
+    let highest = _mm256_max_pd(values_high, swapped);
+
+    let swapped = _mm256_permute_pd::<0b0101>(highest);
+    let highest = _mm256_max_pd(highest, swapped);
+
+    let high_mask = _mm256_cmp_pd::<_CMP_EQ_OQ>(highest, values_high);
+
+    let index_high = _mm256_blendv_pd(
+        _mm256_set1_pd(if LAST { -1.0 } else { f64::MAX }),
+        index_high,
+        high_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256d, [f64; 4]>(values_high);
+    let index_array = std::mem::transmute::<__m256d, [f64; 4]>(index_high);
+
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(max_index);
+    let index = *index_array.get_unchecked(max_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmax_avx512<const LAST: bool>(arr: &[f64]) -> (f64, usize) {
+    let mut index_high = _mm512_setzero_si512();
+    let mut new_index_high = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi64(8);
+
+    let mut values_high = _mm512_set1_pd(arr[0]);
+
+    arr.chunks(8).for_each(|step| {
+        let mask = (u16::MAX >> (16 - step.len())) as __mmask8;
+
+        let new_values = _mm512_maskz_loadu_pd(mask, step.as_ptr());
+        let gt_mask = if LAST {
+            _mm512_mask_cmp_pd_mask::<_CMP_GE_OQ>(mask, new_values, values_high)
+        } else {
+            _mm512_mask_cmp_pd_mask::<_CMP_GT_OQ>(mask, new_values, values_high)
+        };
+
+        values_high = _mm512_mask_blend_pd(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi64(gt_mask, index_high, new_index_high);
+
+        new_index_high = _mm512_add_epi64(new_index_high, increment);
+    });
+
+    horizontal_argmax_avx512::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmax_avx512<const LAST: bool>(
+    values_high: __m512d,
+    index_high: __m512i,
+) -> (f64, usize) {
+    let value = _mm512_reduce_max_pd(values_high);
+    let high_mask = _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(values_high, _mm512_set1_pd(value));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi64(high_mask, index_high)
+    } else {
+        _mm512_mask_reduce_min_epi64(high_mask, index_high)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argminmax_f64(arr: &[f64]) -> Option<(usize, usize)> {
+    minmax_with_indices_f64(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_f64(arr: &[f64]) -> Option<MinMaxResult<f64>> {
+    if simd_level() == SimdLevel::Avx512 {
+        return (!arr.is_empty()).then(|| unsafe { core_argminmax_avx512(arr) });
+    }
+
+    let mut simd_func: unsafe fn(&[f64], usize) -> MinMaxResult<f64> = core_argminmax;
+    let mut mod_size = 2;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argminmax_avx;
+        mod_size = 4;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, rem.len()) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => {
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, 0) };
+            Some((sim_min_result, sim_max_result))
+        }
+        (None, None) => None,
+    }
+}
+
+unsafe fn core_argminmax(sim_arr: &[f64], rem_offset: usize) -> MinMaxResult<f64> {
+    let offset = _mm_set1_pd(rem_offset as f64);
+    let mut index_low = _mm_add_pd(_mm_set_pd(1.0, 0.0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm_set1_pd(2.0);
+
+    let mut values_low = _mm_loadu_pd(sim_arr.as_ptr());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index = _mm_add_pd(new_index, increment);
+
+        let new_values = _mm_loadu_pd(step.as_ptr());
+        let lt_mask = _mm_cmplt_pd(new_values, values_low);
+        let gt_mask = _mm_cmpgt_pd(new_values, values_high);
+
+        values_low = _mm_min_pd(new_values, values_low);
+        index_low = _mm_or_pd(
+            _mm_and_pd(new_index, lt_mask),
+            _mm_andnot_pd(lt_mask, index_low),
+        );
+
+        values_high = _mm_max_pd(new_values, values_high);
+        index_high = _mm_or_pd(
+            _mm_and_pd(new_index, gt_mask),
+            _mm_andnot_pd(gt_mask, index_high),
+        );
+    });
+
+    (
+        horizontal_argmin::<false>(values_low, index_low),
+        horizontal_argmax::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx")]
+unsafe fn core_argminmax_avx(sim_arr: &[f64], rem_offset: usize) -> MinMaxResult<f64> {
+    let offset = _mm256_set1_pd(rem_offset as f64);
+    let mut index_low = _mm256_add_pd(_mm256_set_pd(3.0, 2.0, 1.0, 0.0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm256_set1_pd(4.0);
+
+    let mut values_low = _mm256_loadu_pd(sim_arr.as_ptr());
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index = _mm256_add_pd(new_index, increment);
+
+        let new_values = _mm256_loadu_pd(step.as_ptr());
+        let lt_mask = _mm256_cmp_pd::<_CMP_LT_OQ>(new_values, values_low);
+        let gt_mask = _mm256_cmp_pd::<_CMP_GT_OQ>(new_values, values_high);
+
+        values_low = _mm256_min_pd(new_values, values_low);
+        index_low = _mm256_blendv_pd(index_low, new_index, lt_mask);
+
+        values_high = _mm256_max_pd(new_values, values_high);
+        index_high = _mm256_blendv_pd(index_high, new_index, gt_mask);
+    });
+
+    (
+        horizontal_argmin_avx::<false>(values_low, index_low),
+        horizontal_argmax_avx::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argminmax_avx512(arr: &[f64]) -> MinMaxResult<f64> {
+    let mut index_low = _mm512_setzero_si512();
+    let mut index_high = index_low;
+    let mut new_index = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi64(8);
+
+    let mut values_low = _mm512_set1_pd(arr[0]);
+    let mut values_high = values_low;
+
+    arr.chunks(8).for_each(|step| {
+        let mask = (u16::MAX >> (16 - step.len())) as __mmask8;
+
+        let new_values = _mm512_maskz_loadu_pd(mask, step.as_ptr());
+        let lt_mask = _mm512_mask_cmp_pd_mask::<_CMP_LT_OQ>(mask, new_values, values_low);
+        let gt_mask = _mm512_mask_cmp_pd_mask::<_CMP_GT_OQ>(mask, new_values, values_high);
+
+        values_low = _mm512_mask_blend_pd(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi64(lt_mask, index_low, new_index);
+
+        values_high = _mm512_mask_blend_pd(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi64(gt_mask, index_high, new_index);
+
+        new_index = _mm512_add_epi64(new_index, increment);
+    });
+
+    (
+        horizontal_argmin_avx512::<false>(values_low, index_low),
+        horizontal_argmax_avx512::<false>(values_high, index_high),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        argmax_f64, argmax_f64_last, argmin_f64, argmin_f64_last, argminmax_f64, core_argmax,
+        core_argmax_avx, core_argmax_avx512, core_argmin, core_argmin_avx, core_argmin_avx512,
+        core_argminmax, core_argminmax_avx512, simple_argmax, simple_argmin,
+    };
+    use crate::generic::{simple_argmax_last, simple_argmin_last};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_f64(n: usize) -> Vec<f64> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(f64::MIN / 4.0, f64::MAX / 4.0);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_f64(1025);
+        assert_eq!(data.len() % 4, 1);
+
+        let min_index = argmin_f64(&data).unwrap();
+        let max_index = argmax_f64(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_sse2_kernels_return_the_same_results() {
+        let data = get_array_f64(1024);
+
+        let (_, min_index) = unsafe { core_argmin::<false>(&data, 0) };
+        let (_, max_index) = unsafe { core_argmax::<false>(&data, 0) };
+        let ((_, minmax_low), (_, minmax_high)) = unsafe { core_argminmax(&data, 0) };
+
+        assert_eq!(simple_argmin(&data), min_index);
+        assert_eq!(simple_argmax(&data), max_index);
+        assert_eq!(simple_argmin(&data), minmax_low);
+        assert_eq!(simple_argmax(&data), minmax_high);
+    }
+
+    #[test]
+    fn test_avx512_kernels_handle_masked_tail() {
+        if !(is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")) {
+            return;
+        }
+
+        for n in [1, 5, 8, 15, 16, 17, 1025] {
+            let data = get_array_f64(n);
+            let argmin_index = simple_argmin(&data);
+            let argmax_index = simple_argmax(&data);
+
+            assert_eq!(
+                unsafe { core_argmin_avx512::<false>(&data) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx512::<false>(&data) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let data = [
+            10.,
+            f64::MAX,
+            6.,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+            f64::MAX,
+            10_000.0,
+            f64::MAX,
+            f64::NEG_INFINITY,
+        ];
+        let argmin_index = simple_argmin(&data);
+        let argmin_simd_index = argmin_f64(&data).unwrap();
+        assert_eq!(argmin_index, argmin_simd_index);
+        assert_eq!(argmin_index, 3);
+
+        let argmax_index = simple_argmax(&data);
+        let argmax_simd_index = argmax_f64(&data).unwrap();
+        assert_eq!(argmax_index, argmax_simd_index);
+        assert_eq!(argmax_index, 1);
+
+        let ((_, min_index), (_, max_index)) = unsafe { core_argminmax(&data[1..], 1) };
+        assert_eq!((min_index, max_index), (3, 1));
+    }
+
+    #[test]
+    fn test_last_index_is_returned_when_identical_values_found() {
+        for n in [8, 9, 1025, 4099] {
+            let data: Vec<f64> = get_array_f64(n).iter().map(|x| (x % 7.0).floor()).collect();
+            assert_eq!(argmin_f64_last(&data), Some(simple_argmin_last(&data)));
+            assert_eq!(argmax_f64_last(&data), Some(simple_argmax_last(&data)));
+
+            let sim = &data[..n - n % 8];
+            let (min_index, max_index) = (simple_argmin_last(sim), simple_argmax_last(sim));
+            assert_eq!(unsafe { core_argmin::<true>(sim, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax::<true>(sim, 0) }.1, max_index);
+            if is_x86_feature_detected!("avx") {
+                assert_eq!(unsafe { core_argmin_avx::<true>(sim, 0) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx::<true>(sim, 0) }.1, max_index);
+            }
+            if is_x86_feature_detected!("avx512f") {
+                assert_eq!(unsafe { core_argmin_avx512::<true>(sim) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx512::<true>(sim) }.1, max_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [7, 8, 9, 1025] {
+            let data = get_array_f64(n);
+            let (min_index, max_index) = argminmax_f64(&data).unwrap();
+            assert_eq!(min_index, simple_argmin(&data));
+            assert_eq!(max_index, simple_argmax(&data));
+        }
+    }
+}
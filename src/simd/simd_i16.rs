@@ -1,310 +1,643 @@
-use crate::generic::{simple_argmax, simple_argmin};
-use crate::task::{find_final_index_max, find_final_index_min, split_array};
+use crate::dispatch::{simd_level, SimdLevel};
+use crate::generic::{simple_argmax, simple_argmin, simple_argminmax};
+use crate::task::{
+    blocked_argmax, blocked_argmin, blocked_argminmax, merge_max, merge_max_tie, merge_min,
+    merge_min_tie, simple_argmax_tie, simple_argmin_tie, split_array, with_value, with_values,
+    MinMaxResult, STEP_BLOCK_SIZE_AVX2, STEP_BLOCK_SIZE_AVX512, STEP_BLOCK_SIZE_SSE,
+};
 use std::arch::x86_64::*;
 
 pub fn argmin_i16(arr: &[i16]) -> Option<usize> {
-    let n = arr.len();
-    let mut simd_func: unsafe fn(&[i16], usize) -> (i16, usize) = core_argmin;
-    let mut mod_size = 4;
+    min_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmin_i16`], but the last index is returned among equal minima.
+pub fn argmin_i16_last(arr: &[i16]) -> Option<usize> {
+    min_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_i16(arr: &[i16]) -> Option<(i16, usize)> {
+    min_with_ties::<false>(arr)
+}
 
-    if (17..std::i16::MAX).contains(&(n as i16)) {
-        simd_func = core_argmin_ext;
-        mod_size = 8;
+fn min_with_ties<const LAST: bool>(arr: &[i16]) -> Option<(i16, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return (!arr.is_empty()).then(|| with_value(arr, simple_argmin_tie::<_, LAST>(arr)));
+    }
+
+    let mut simd_func: unsafe fn(&[i16], usize) -> (i16, usize) = core_argmin::<LAST>;
+    let mut mod_size = 8;
+
+    if simd_level() == SimdLevel::Avx512 {
+        simd_func = core_argmin_avx512::<LAST>;
+        mod_size = 32;
+    } else if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_avx2::<LAST>;
+        mod_size = 16;
     };
 
     match split_array(arr, mod_size) {
         (Some(rem), Some(sim)) => {
-            let rem_min_index = simple_argmin(rem);
+            let rem_min_index = simple_argmin_tie::<_, LAST>(rem);
             let rem_result = (rem[rem_min_index], rem_min_index);
             let sim_result = unsafe { simd_func(sim, rem.len()) };
-            find_final_index_min(rem_result, sim_result)
+            Some(merge_min_tie::<_, LAST>(rem_result, sim_result))
         }
-        (Some(rem), None) => Some(simple_argmin(rem)),
+        (Some(rem), None) => Some(with_value(rem, simple_argmin_tie::<_, LAST>(rem))),
         (None, Some(sim)) => {
             let sim_result = unsafe { simd_func(sim, 0) };
-            Some(sim_result.1)
+            Some(sim_result)
         }
         (None, None) => None,
     }
 }
 
-unsafe fn core_argmin(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
-    let offset = _mm_set1_epi32(rem_offset as i32);
-    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
-
-    let increment = _mm_set1_epi32(4);
-    let mut new_index_low = index_low;
-
-    let mut values_low = _mm_set_epi32(
-        sim_arr[3] as i32,
-        sim_arr[2] as i32,
-        sim_arr[1] as i32,
-        sim_arr[0] as i32,
-    );
-
-    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
-        new_index_low = _mm_add_epi32(new_index_low, increment);
-
-        let new_values = _mm_set_epi32(
-            step[3] as i32,
-            step[2] as i32,
-            step[1] as i32,
-            step[0] as i32,
-        );
-
-        let lt_mask = _mm_cmplt_epi32(new_values, values_low);
-
-        values_low = _mm_or_si128(
-            _mm_and_si128(new_values, lt_mask),
-            _mm_andnot_si128(lt_mask, values_low),
-        );
-        index_low = _mm_or_si128(
-            _mm_and_si128(new_index_low, lt_mask),
-            _mm_andnot_si128(lt_mask, index_low),
-        );
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmin<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    blocked_argmin::<_, LAST>(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_SSE,
+        core_argmin_block::<LAST>,
+    )
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmin_block<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    let mut step_low = _mm_setzero_si128();
+    let mut new_step = step_low;
+
+    let increment = _mm_set1_epi16(1);
+
+    let mut values_low = _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i);
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_step = _mm_add_epi16(new_step, increment);
+
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        let lt_mask = if LAST {
+            _mm_or_si128(
+                _mm_cmplt_epi16(new_values, values_low),
+                _mm_cmpeq_epi16(new_values, values_low),
+            )
+        } else {
+            _mm_cmplt_epi16(new_values, values_low)
+        };
+
+        values_low = _mm_min_epi16(values_low, new_values);
+        step_low = _mm_blendv_epi8(step_low, new_step, lt_mask);
     });
 
-    let highpack = _mm_unpackhi_epi32(values_low, values_low);
-    let lowpack = _mm_unpacklo_epi32(values_low, values_low);
-    let lowest = _mm_min_epi32(highpack, lowpack);
+    let (value, index) = horizontal_argmin::<LAST>(values_low, step_low);
+
+    (value, rem_offset + index)
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn horizontal_argmin<const LAST: bool>(
+    values_low: __m128i,
+    step_low: __m128i,
+) -> (i16, usize) {
+    let value_array = std::mem::transmute::<__m128i, [i16; 8]>(values_low);
+    let step_array = std::mem::transmute::<__m128i, [u16; 8]>(step_low);
+
+    let value = value_array[simple_argmin(&value_array)];
 
-    let highpack = _mm_unpackhi_epi32(lowest, lowest);
-    let lowpack = _mm_unpacklo_epi32(lowest, lowest);
-    let lowest = _mm_min_epi32(highpack, lowpack);
+    (value, lane_index::<LAST>(&value_array, &step_array, value))
+}
 
-    let low_mask = _mm_cmpeq_epi32(lowest, values_low);
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_avx2<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    blocked_argmin::<_, LAST>(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_AVX2,
+        core_argmin_block_avx2::<LAST>,
+    )
+}
 
-    index_low = _mm_or_si128(
-        _mm_and_si128(index_low, low_mask),
-        _mm_andnot_si128(low_mask, _mm_set1_epi32(std::i32::MAX)),
-    );
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_block_avx2<const LAST: bool>(
+    sim_arr: &[i16],
+    rem_offset: usize,
+) -> (i16, usize) {
+    let mut step_low = _mm256_setzero_si256();
+    let mut new_step = step_low;
+
+    let increment = _mm256_set1_epi16(1);
+
+    let mut values_low = _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i);
+
+    sim_arr.chunks_exact(16).skip(1).for_each(|step| {
+        new_step = _mm256_add_epi16(new_step, increment);
+
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        let lt_mask = if LAST {
+            _mm256_or_si256(
+                _mm256_cmpgt_epi16(values_low, new_values),
+                _mm256_cmpeq_epi16(values_low, new_values),
+            )
+        } else {
+            _mm256_cmpgt_epi16(values_low, new_values)
+        };
+
+        values_low = _mm256_min_epi16(values_low, new_values);
+        step_low = _mm256_blendv_epi8(step_low, new_step, lt_mask);
+    });
 
-    let value_array = std::mem::transmute::<__m128i, [u32; 4]>(values_low);
-    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_low);
+    let (value, index) = horizontal_argmin_avx2::<LAST>(values_low, step_low);
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+    (value, rem_offset + index)
+}
 
-    (value as i16, index as usize)
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmin_avx2<const LAST: bool>(
+    values_low: __m256i,
+    step_low: __m256i,
+) -> (i16, usize) {
+    let value_array = std::mem::transmute::<__m256i, [i16; 16]>(values_low);
+    let step_array = std::mem::transmute::<__m256i, [u16; 16]>(step_low);
+
+    let value = value_array[simple_argmin(&value_array)];
+
+    (value, lane_index::<LAST>(&value_array, &step_array, value))
 }
 
-unsafe fn core_argmin_ext(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
-    let offset = _mm_set1_epi16(rem_offset as i16);
-    let mut index_low = _mm_add_epi16(_mm_set_epi16(7, 6, 5, 4, 3, 2, 1, 0), offset);
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argmin_avx512<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    blocked_argmin::<_, LAST>(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_AVX512,
+        core_argmin_block_avx512::<LAST>,
+    )
+}
 
-    let increment = _mm_set1_epi16(8);
-    let mut new_index_low = index_low;
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argmin_block_avx512<const LAST: bool>(
+    sim_arr: &[i16],
+    rem_offset: usize,
+) -> (i16, usize) {
+    let mut step_low = _mm512_setzero_si512();
+    let mut new_step = step_low;
 
-    let mut values_low = _mm_set_epi16(
-        sim_arr[7], sim_arr[6], sim_arr[5], sim_arr[4], sim_arr[3], sim_arr[2], sim_arr[1],
-        sim_arr[0],
-    );
+    let increment = _mm512_set1_epi16(1);
 
-    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
-        new_index_low = _mm_add_epi16(new_index_low, increment);
+    let mut values_low = _mm512_loadu_si512(sim_arr.as_ptr() as *const __m512i);
 
-        let new_values = _mm_set_epi16(
-            step[7], step[6], step[5], step[4], step[3], step[2], step[1], step[0],
-        );
+    sim_arr.chunks_exact(32).skip(1).for_each(|step| {
+        new_step = _mm512_add_epi16(new_step, increment);
 
-        let lt_mask = _mm_cmplt_epi16(new_values, values_low);
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        let lt_mask = if LAST {
+            _mm512_cmple_epi16_mask(new_values, values_low)
+        } else {
+            _mm512_cmplt_epi16_mask(new_values, values_low)
+        };
 
-        values_low = _mm_or_si128(
-            _mm_and_si128(new_values, lt_mask),
-            _mm_andnot_si128(lt_mask, values_low),
-        );
-        index_low = _mm_or_si128(
-            _mm_and_si128(new_index_low, lt_mask),
-            _mm_andnot_si128(lt_mask, index_low),
-        );
+        values_low = _mm512_min_epi16(values_low, new_values);
+        step_low = _mm512_mask_blend_epi16(lt_mask, step_low, new_step);
     });
 
-    let highpack = _mm_unpackhi_epi16(values_low, values_low);
-    let lowpack = _mm_unpacklo_epi16(values_low, values_low);
-    let lowest = _mm_min_epi16(highpack, lowpack);
+    let (value, index) = horizontal_argmin_avx512::<LAST>(values_low, step_low);
 
-    let highpack = _mm_unpackhi_epi16(lowest, lowest);
-    let lowpack = _mm_unpacklo_epi16(lowest, lowest);
-    let lowest = _mm_min_epi16(highpack, lowpack);
+    (value, rem_offset + index)
+}
 
-    let highpack = _mm_unpackhi_epi16(lowest, lowest);
-    let lowpack = _mm_unpacklo_epi16(lowest, lowest);
-    let lowest = _mm_min_epi16(highpack, lowpack);
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmin_avx512<const LAST: bool>(
+    values_low: __m512i,
+    step_low: __m512i,
+) -> (i16, usize) {
+    let value_array = std::mem::transmute::<__m512i, [i16; 32]>(values_low);
+    let step_array = std::mem::transmute::<__m512i, [u16; 32]>(step_low);
 
-    let low_mask = _mm_cmpeq_epi16(lowest, values_low);
+    let value = value_array[simple_argmin(&value_array)];
 
-    index_low = _mm_or_si128(
-        _mm_and_si128(index_low, low_mask),
-        _mm_andnot_si128(low_mask, _mm_set1_epi16(std::i16::MAX)),
-    );
+    (value, lane_index::<LAST>(&value_array, &step_array, value))
+}
 
-    let value_array = std::mem::transmute::<__m128i, [i16; 8]>(values_low);
-    let index_array = std::mem::transmute::<__m128i, [i16; 8]>(index_low);
+pub fn argmax_i16(arr: &[i16]) -> Option<usize> {
+    max_with_ties::<false>(arr).map(|(_, index)| index)
+}
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+/// Like [`argmax_i16`], but the last index is returned among equal maxima.
+pub fn argmax_i16_last(arr: &[i16]) -> Option<usize> {
+    max_with_ties::<true>(arr).map(|(_, index)| index)
+}
 
-    (value, index as usize)
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_i16(arr: &[i16]) -> Option<(i16, usize)> {
+    max_with_ties::<false>(arr)
 }
 
-pub fn argmax_i16(arr: &[i16]) -> Option<usize> {
-    let n = arr.len();
-    let mut simd_func: unsafe fn(&[i16], usize) -> (i16, usize) = core_argmax;
-    let mut mod_size = 4;
+fn max_with_ties<const LAST: bool>(arr: &[i16]) -> Option<(i16, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return (!arr.is_empty()).then(|| with_value(arr, simple_argmax_tie::<_, LAST>(arr)));
+    }
 
-    if (17..=std::i16::MAX).contains(&(n as i16)) {
-        simd_func = core_argmax_ext;
-        mod_size = 8;
+    let mut simd_func: unsafe fn(&[i16], usize) -> (i16, usize) = core_argmax::<LAST>;
+    let mut mod_size = 8;
+
+    if simd_level() == SimdLevel::Avx512 {
+        simd_func = core_argmax_avx512::<LAST>;
+        mod_size = 32;
+    } else if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_avx2::<LAST>;
+        mod_size = 16;
     };
 
     match split_array(arr, mod_size) {
         (Some(rem), Some(sim)) => {
-            let rem_min_index = simple_argmax(rem);
+            let rem_min_index = simple_argmax_tie::<_, LAST>(rem);
             let rem_result = (rem[rem_min_index], rem_min_index);
             let sim_result = unsafe { simd_func(sim, rem.len()) };
-            find_final_index_max(rem_result, sim_result)
+            Some(merge_max_tie::<_, LAST>(rem_result, sim_result))
         }
-        (Some(rem), None) => Some(simple_argmax(rem)),
+        (Some(rem), None) => Some(with_value(rem, simple_argmax_tie::<_, LAST>(rem))),
         (None, Some(sim)) => {
             let sim_result = unsafe { simd_func(sim, 0) };
-            Some(sim_result.1)
+            Some(sim_result)
         }
         (None, None) => None,
     }
 }
 
-unsafe fn core_argmax(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
-    let offset = _mm_set1_epi32(rem_offset as i32);
-    let mut index_high = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
-    let mut new_index_high = index_high;
-
-    let increment = _mm_set1_epi32(4);
-
-    let mut values_high = _mm_set_epi32(
-        sim_arr[3] as i32,
-        sim_arr[2] as i32,
-        sim_arr[1] as i32,
-        sim_arr[0] as i32,
-    );
-
-    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
-        new_index_high = _mm_add_epi32(new_index_high, increment);
-
-        let new_values = _mm_set_epi32(
-            step[3] as i32,
-            step[2] as i32,
-            step[1] as i32,
-            step[0] as i32,
-        );
-
-        let gt_mask = _mm_cmpgt_epi32(new_values, values_high);
-
-        values_high = _mm_or_si128(
-            _mm_and_si128(new_values, gt_mask),
-            _mm_andnot_si128(gt_mask, values_high),
-        );
-        index_high = _mm_or_si128(
-            _mm_and_si128(new_index_high, gt_mask),
-            _mm_andnot_si128(gt_mask, index_high),
-        );
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmax<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    blocked_argmax::<_, LAST>(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_SSE,
+        core_argmax_block::<LAST>,
+    )
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmax_block<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    let mut step_high = _mm_setzero_si128();
+    let mut new_step = step_high;
+
+    let increment = _mm_set1_epi16(1);
+
+    let mut values_high = _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i);
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_step = _mm_add_epi16(new_step, increment);
+
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        let gt_mask = if LAST {
+            _mm_or_si128(
+                _mm_cmpgt_epi16(new_values, values_high),
+                _mm_cmpeq_epi16(new_values, values_high),
+            )
+        } else {
+            _mm_cmpgt_epi16(new_values, values_high)
+        };
+
+        values_high = _mm_max_epi16(values_high, new_values);
+        step_high = _mm_blendv_epi8(step_high, new_step, gt_mask);
+    });
+
+    let (value, index) = horizontal_argmax::<LAST>(values_high, step_high);
+
+    (value, rem_offset + index)
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn horizontal_argmax<const LAST: bool>(
+    values_high: __m128i,
+    step_high: __m128i,
+) -> (i16, usize) {
+    let value_array = std::mem::transmute::<__m128i, [i16; 8]>(values_high);
+    let step_array = std::mem::transmute::<__m128i, [u16; 8]>(step_high);
+
+    let value = value_array[simple_argmax(&value_array)];
+
+    (value, lane_index::<LAST>(&value_array, &step_array, value))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_avx2<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    blocked_argmax::<_, LAST>(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_AVX2,
+        core_argmax_block_avx2::<LAST>,
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_block_avx2<const LAST: bool>(
+    sim_arr: &[i16],
+    rem_offset: usize,
+) -> (i16, usize) {
+    let mut step_high = _mm256_setzero_si256();
+    let mut new_step = step_high;
+
+    let increment = _mm256_set1_epi16(1);
+
+    let mut values_high = _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i);
+
+    sim_arr.chunks_exact(16).skip(1).for_each(|step| {
+        new_step = _mm256_add_epi16(new_step, increment);
+
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        let gt_mask = if LAST {
+            _mm256_or_si256(
+                _mm256_cmpgt_epi16(new_values, values_high),
+                _mm256_cmpeq_epi16(new_values, values_high),
+            )
+        } else {
+            _mm256_cmpgt_epi16(new_values, values_high)
+        };
+
+        values_high = _mm256_max_epi16(values_high, new_values);
+        step_high = _mm256_blendv_epi8(step_high, new_step, gt_mask);
+    });
+
+    let (value, index) = horizontal_argmax_avx2::<LAST>(values_high, step_high);
+
+    (value, rem_offset + index)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmax_avx2<const LAST: bool>(
+    values_high: __m256i,
+    step_high: __m256i,
+) -> (i16, usize) {
+    let value_array = std::mem::transmute::<__m256i, [i16; 16]>(values_high);
+    let step_array = std::mem::transmute::<__m256i, [u16; 16]>(step_high);
+
+    let value = value_array[simple_argmax(&value_array)];
+
+    (value, lane_index::<LAST>(&value_array, &step_array, value))
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argmax_avx512<const LAST: bool>(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
+    blocked_argmax::<_, LAST>(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_AVX512,
+        core_argmax_block_avx512::<LAST>,
+    )
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argmax_block_avx512<const LAST: bool>(
+    sim_arr: &[i16],
+    rem_offset: usize,
+) -> (i16, usize) {
+    let mut step_high = _mm512_setzero_si512();
+    let mut new_step = step_high;
+
+    let increment = _mm512_set1_epi16(1);
+
+    let mut values_high = _mm512_loadu_si512(sim_arr.as_ptr() as *const __m512i);
+
+    sim_arr.chunks_exact(32).skip(1).for_each(|step| {
+        new_step = _mm512_add_epi16(new_step, increment);
+
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        let gt_mask = if LAST {
+            _mm512_cmpge_epi16_mask(new_values, values_high)
+        } else {
+            _mm512_cmpgt_epi16_mask(new_values, values_high)
+        };
+
+        values_high = _mm512_max_epi16(values_high, new_values);
+        step_high = _mm512_mask_blend_epi16(gt_mask, step_high, new_step);
     });
 
-    let highpack = _mm_unpackhi_epi32(values_high, values_high);
-    let lowpack = _mm_unpacklo_epi32(values_high, values_high);
-    let highest = _mm_max_epi32(highpack, lowpack);
+    let (value, index) = horizontal_argmax_avx512::<LAST>(values_high, step_high);
+
+    (value, rem_offset + index)
+}
 
-    let highpack = _mm_unpackhi_epi32(highest, highest);
-    let lowpack = _mm_unpacklo_epi32(highest, highest);
-    let highest = _mm_max_epi32(highpack, lowpack);
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmax_avx512<const LAST: bool>(
+    values_high: __m512i,
+    step_high: __m512i,
+) -> (i16, usize) {
+    let value_array = std::mem::transmute::<__m512i, [i16; 32]>(values_high);
+    let step_array = std::mem::transmute::<__m512i, [u16; 32]>(step_high);
 
-    let high_mask = _mm_cmpeq_epi32(highest, values_high);
+    let value = value_array[simple_argmax(&value_array)];
 
-    index_high = _mm_or_si128(
-        _mm_and_si128(index_high, high_mask),
-        _mm_andnot_si128(high_mask, _mm_set1_epi32(std::i32::MAX)),
-    );
+    (value, lane_index::<LAST>(&value_array, &step_array, value))
+}
 
-    let value_array = std::mem::transmute::<__m128i, [u32; 4]>(values_high);
-    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_high);
+pub fn argminmax_i16(arr: &[i16]) -> Option<(usize, usize)> {
+    minmax_with_indices_i16(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_i16(arr: &[i16]) -> Option<MinMaxResult<i16>> {
+    if simd_level() == SimdLevel::Scalar {
+        return (!arr.is_empty()).then(|| with_values(arr, simple_argminmax(arr)));
+    }
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+    let mut simd_func: unsafe fn(&[i16], usize) -> MinMaxResult<i16> = core_argminmax;
+    let mut mod_size = 8;
+
+    if simd_level() == SimdLevel::Avx512 {
+        simd_func = core_argminmax_avx512;
+        mod_size = 32;
+    } else if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argminmax_avx2;
+        mod_size = 16;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, rem.len()) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => {
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, 0) };
+            Some((sim_min_result, sim_max_result))
+        }
+        (None, None) => None,
+    }
+}
 
-    (value as i16, index as usize)
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argminmax(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    blocked_argminmax(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_SSE,
+        core_argminmax_block,
+    )
 }
 
-unsafe fn core_argmax_ext(sim_arr: &[i16], rem_offset: usize) -> (i16, usize) {
-    let offset = _mm_set1_epi16(rem_offset as i16);
-    let mut index_high = _mm_add_epi16(_mm_set_epi16(7, 6, 5, 4, 3, 2, 1, 0), offset);
-    let mut new_index_high = index_high;
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argminmax_block(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    let mut step_low = _mm_setzero_si128();
+    let mut step_high = step_low;
+    let mut new_step = step_low;
 
-    let increment = _mm_set1_epi16(8);
+    let increment = _mm_set1_epi16(1);
 
-    let mut values_high = _mm_set_epi16(
-        sim_arr[7], sim_arr[6], sim_arr[5], sim_arr[4], sim_arr[3], sim_arr[2], sim_arr[1],
-        sim_arr[0],
-    );
+    let mut values_low = _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i);
+    let mut values_high = values_low;
 
     sim_arr.chunks_exact(8).skip(1).for_each(|step| {
-        new_index_high = _mm_add_epi16(new_index_high, increment);
+        new_step = _mm_add_epi16(new_step, increment);
+
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        let lt_mask = _mm_cmplt_epi16(new_values, values_low);
 
-        let new_values = _mm_set_epi16(
-            step[7], step[6], step[5], step[4], step[3], step[2], step[1], step[0],
-        );
+        values_low = _mm_min_epi16(values_low, new_values);
+        step_low = _mm_blendv_epi8(step_low, new_step, lt_mask);
 
         let gt_mask = _mm_cmpgt_epi16(new_values, values_high);
 
-        values_high = _mm_or_si128(
-            _mm_and_si128(new_values, gt_mask),
-            _mm_andnot_si128(gt_mask, values_high),
-        );
-        index_high = _mm_or_si128(
-            _mm_and_si128(new_index_high, gt_mask),
-            _mm_andnot_si128(gt_mask, index_high),
-        );
+        values_high = _mm_max_epi16(values_high, new_values);
+        step_high = _mm_blendv_epi8(step_high, new_step, gt_mask);
     });
 
-    let highpack = _mm_unpackhi_epi16(values_high, values_high);
-    let lowpack = _mm_unpacklo_epi16(values_high, values_high);
-    let highest = _mm_max_epi16(highpack, lowpack);
+    let (low, low_index) = horizontal_argmin::<false>(values_low, step_low);
+    let (high, high_index) = horizontal_argmax::<false>(values_high, step_high);
 
-    let highpack = _mm_unpackhi_epi16(highest, highest);
-    let lowpack = _mm_unpacklo_epi16(highest, highest);
-    let highest = _mm_max_epi16(highpack, lowpack);
+    (
+        (low, rem_offset + low_index),
+        (high, rem_offset + high_index),
+    )
+}
 
-    let highpack = _mm_unpackhi_epi16(highest, highest);
-    let lowpack = _mm_unpacklo_epi16(highest, highest);
-    let highest = _mm_max_epi16(highpack, lowpack);
+#[target_feature(enable = "avx2")]
+unsafe fn core_argminmax_avx2(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    blocked_argminmax(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_AVX2,
+        core_argminmax_block_avx2,
+    )
+}
 
-    let high_mask = _mm_cmpeq_epi16(highest, values_high);
+#[target_feature(enable = "avx2")]
+unsafe fn core_argminmax_block_avx2(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    let mut step_low = _mm256_setzero_si256();
+    let mut step_high = step_low;
+    let mut new_step = step_low;
 
-    index_high = _mm_or_si128(
-        _mm_and_si128(index_high, high_mask),
-        _mm_andnot_si128(high_mask, _mm_set1_epi16(std::i16::MAX)),
-    );
+    let increment = _mm256_set1_epi16(1);
 
-    let value_array = std::mem::transmute::<__m128i, [i16; 8]>(values_high);
-    let index_array = std::mem::transmute::<__m128i, [i16; 8]>(index_high);
+    let mut values_low = _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i);
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(16).skip(1).for_each(|step| {
+        new_step = _mm256_add_epi16(new_step, increment);
+
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        let lt_mask = _mm256_cmpgt_epi16(values_low, new_values);
+
+        values_low = _mm256_min_epi16(values_low, new_values);
+        step_low = _mm256_blendv_epi8(step_low, new_step, lt_mask);
+
+        let gt_mask = _mm256_cmpgt_epi16(new_values, values_high);
+
+        values_high = _mm256_max_epi16(values_high, new_values);
+        step_high = _mm256_blendv_epi8(step_high, new_step, gt_mask);
+    });
+
+    let (low, low_index) = horizontal_argmin_avx2::<false>(values_low, step_low);
+    let (high, high_index) = horizontal_argmax_avx2::<false>(values_high, step_high);
+
+    (
+        (low, rem_offset + low_index),
+        (high, rem_offset + high_index),
+    )
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argminmax_avx512(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    blocked_argminmax(
+        sim_arr,
+        rem_offset,
+        STEP_BLOCK_SIZE_AVX512,
+        core_argminmax_block_avx512,
+    )
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argminmax_block_avx512(sim_arr: &[i16], rem_offset: usize) -> MinMaxResult<i16> {
+    let mut step_low = _mm512_setzero_si512();
+    let mut step_high = step_low;
+    let mut new_step = step_low;
+
+    let increment = _mm512_set1_epi16(1);
+
+    let mut values_low = _mm512_loadu_si512(sim_arr.as_ptr() as *const __m512i);
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(32).skip(1).for_each(|step| {
+        new_step = _mm512_add_epi16(new_step, increment);
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        let lt_mask = _mm512_cmplt_epi16_mask(new_values, values_low);
 
-    (value, index as usize)
+        values_low = _mm512_min_epi16(values_low, new_values);
+        step_low = _mm512_mask_blend_epi16(lt_mask, step_low, new_step);
+
+        let gt_mask = _mm512_cmpgt_epi16_mask(new_values, values_high);
+
+        values_high = _mm512_max_epi16(values_high, new_values);
+        step_high = _mm512_mask_blend_epi16(gt_mask, step_high, new_step);
+    });
+
+    let (low, low_index) = horizontal_argmin_avx512::<false>(values_low, step_low);
+    let (high, high_index) = horizontal_argmax_avx512::<false>(values_high, step_high);
+
+    (
+        (low, rem_offset + low_index),
+        (high, rem_offset + high_index),
+    )
+}
+
+/// Index of the first element equal to `value`, or the last one when `LAST`,
+/// rebuilt from the lane it sits in and the step counter recorded for that lane.
+#[inline]
+fn lane_index<const LAST: bool>(value_array: &[i16], step_array: &[u16], value: i16) -> usize {
+    let lanes = value_array.len();
+
+    let indices = value_array
+        .iter()
+        .zip(step_array)
+        .enumerate()
+        .filter(|(_, (lane_value, _))| **lane_value == value)
+        .map(|(lane, (_, step))| *step as usize * lanes + lane);
+    if LAST {
+        indices.max().unwrap()
+    } else {
+        indices.min().unwrap()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{argmax_i16, argmin_i16, simple_argmax, simple_argmin};
+    use super::{
+        argmax_i16, argmax_i16_last, argmin_i16, argmin_i16_last, argminmax_i16, core_argmax,
+        core_argmax_avx2, core_argmax_avx512, core_argmin, core_argmin_avx2, core_argmin_avx512,
+        core_argminmax, core_argminmax_avx2, core_argminmax_avx512, simple_argmax, simple_argmin,
+    };
+    use crate::generic::{simple_argmax_last, simple_argmin_last};
     use rand::{thread_rng, Rng};
     use rand_distr::Uniform;
 
     fn get_array_i16(n: usize) -> Vec<i16> {
         let rng = thread_rng();
-        let uni = Uniform::new_inclusive(std::i16::MIN, std::i16::MAX);
+        let uni = Uniform::new_inclusive(i16::MIN, i16::MAX);
         rng.sample_iter(uni).take(n).collect()
     }
 
@@ -322,6 +655,69 @@ mod tests {
         assert_eq!(argmax_index, max_index);
     }
 
+    #[test]
+    fn test_sse_and_avx2_kernels_return_the_same_results() {
+        let data = get_array_i16(1024);
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        if is_x86_feature_detected!("sse4.1") {
+            assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+            assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, argmax_index);
+            let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(
+                unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx2::<false>(&data, 0) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_kernels_handle_arrays_past_i16_range() {
+        for n in [32_768, 40_000, 65_568, 100_000, 1_100_000] {
+            let mut data: Vec<i16> = get_array_i16(n)
+                .into_iter()
+                .map(|x| x.clamp(i16::MIN + 1, i16::MAX - 1))
+                .collect();
+            data[32_761] = i16::MIN;
+            data[n - 11] = i16::MIN;
+            data[n - 5] = i16::MAX;
+
+            let argmin_index = (n - 11).min(32_761);
+
+            if is_x86_feature_detected!("sse4.1") {
+                assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+                assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, n - 5);
+                let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 3) };
+                assert_eq!((low, high), (argmin_index + 3, n - 2));
+            }
+
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(
+                    unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                    argmin_index
+                );
+                assert_eq!(unsafe { core_argmax_avx2::<false>(&data, 0) }.1, n - 5);
+                let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 3) };
+                assert_eq!((low, high), (argmin_index + 3, n - 2));
+            }
+
+            let data = get_array_i16(n + 3);
+            assert_eq!(argmin_i16(&data), Some(simple_argmin(&data)));
+            assert_eq!(argmax_i16(&data), Some(simple_argmax(&data)));
+        }
+    }
+
     #[test]
     fn test_both_versions_return_the_same_results_short_array() {
         let data = get_array_i16(9);
@@ -336,19 +732,42 @@ mod tests {
         assert_eq!(argmax_index, max_index);
     }
 
+    #[test]
+    fn test_avx512_kernels_return_the_same_results() {
+        if !(is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")) {
+            return;
+        }
+
+        // The last size spans two blocks of step counters.
+        for n in [64, 1024, (32 << 16) + 64] {
+            let mut data: Vec<i16> = get_array_i16(n)
+                .into_iter()
+                .map(|x| x.clamp(i16::MIN + 1, i16::MAX - 1))
+                .collect();
+            data[n - 40] = i16::MIN;
+            data[n - 7] = i16::MIN;
+            data[5] = i16::MAX;
+            data[n - 20] = i16::MAX;
+
+            assert_eq!(unsafe { core_argmin_avx512::<false>(&data, 0) }.1, n - 40);
+            assert_eq!(unsafe { core_argmax_avx512::<false>(&data, 0) }.1, 5);
+            assert_eq!(unsafe { core_argmin_avx512::<true>(&data, 0) }.1, n - 7);
+            assert_eq!(unsafe { core_argmax_avx512::<true>(&data, 0) }.1, n - 20);
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data, 3) };
+            assert_eq!((low, high), (n - 37, 8));
+
+            let data: Vec<i16> = data.iter().map(|x| x % 7).collect();
+            let (min_index, max_index) = (simple_argmin_last(&data), simple_argmax_last(&data));
+            assert_eq!(unsafe { core_argmin_avx512::<true>(&data, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax_avx512::<true>(&data, 0) }.1, max_index);
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data, 0) };
+            assert_eq!((low, high), (simple_argmin(&data), simple_argmax(&data)));
+        }
+    }
+
     #[test]
     fn test_first_index_is_returned_when_identical_values_found() {
-        let data = [
-            10,
-            std::i16::MIN,
-            6,
-            9,
-            9,
-            22,
-            std::i16::MAX,
-            4,
-            std::i16::MAX,
-        ];
+        let data = [10, i16::MIN, 6, 9, 9, 22, i16::MAX, 4, i16::MAX];
         let argmin_index = simple_argmin(&data);
         let argmin_simd_index = argmin_i16(&data).unwrap();
         assert_eq!(argmin_index, argmin_simd_index);
@@ -359,4 +778,32 @@ mod tests {
         assert_eq!(argmax_index, argmax_simd_index);
         assert_eq!(argmax_index, 6);
     }
+
+    #[test]
+    fn test_last_index_is_returned_when_identical_values_found() {
+        for n in [16, 17, 1025, 4099, (16 << 16) + 33] {
+            let data: Vec<i16> = get_array_i16(n).iter().map(|x| x % 7).collect();
+            assert_eq!(argmin_i16_last(&data), Some(simple_argmin_last(&data)));
+            assert_eq!(argmax_i16_last(&data), Some(simple_argmax_last(&data)));
+
+            let sim = &data[..n - n % 16];
+            let (min_index, max_index) = (simple_argmin_last(sim), simple_argmax_last(sim));
+            assert_eq!(unsafe { core_argmin::<true>(sim, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax::<true>(sim, 0) }.1, max_index);
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(unsafe { core_argmin_avx2::<true>(sim, 0) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx2::<true>(sim, 0) }.1, max_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [7, 8, 9, 1025, 40_001] {
+            let data = get_array_i16(n);
+            let (min_index, max_index) = argminmax_i16(&data).unwrap();
+            assert_eq!(min_index, simple_argmin(&data));
+            assert_eq!(max_index, simple_argmax(&data));
+        }
+    }
 }
@@ -0,0 +1,759 @@
+use crate::dispatch::{simd_level, SimdLevel};
+use crate::generic::{simple_argmax, simple_argmin, simple_argminmax};
+use crate::task::{
+    merge_max, merge_max_tie, merge_min, merge_min_tie, simple_argmax_tie, simple_argmin_tie,
+    split_array, with_value, with_values, MinMaxResult,
+};
+use std::arch::x86_64::*;
+
+pub fn argmin_i64(arr: &[i64]) -> Option<usize> {
+    min_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmin_i64`], but the last index is returned among equal minima.
+pub fn argmin_i64_last(arr: &[i64]) -> Option<usize> {
+    min_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_i64(arr: &[i64]) -> Option<(i64, usize)> {
+    min_with_ties::<false>(arr)
+}
+
+fn min_with_ties<const LAST: bool>(arr: &[i64]) -> Option<(i64, usize)> {
+    let mut simd_func: unsafe fn(&[i64], usize) -> (i64, usize) = core_argmin::<LAST>;
+    let mut mod_size = 2;
+
+    match simd_level() {
+        SimdLevel::Avx512 => {
+            return (!arr.is_empty()).then(|| unsafe { core_argmin_avx512::<LAST>(arr) })
+        }
+        SimdLevel::Avx2 => {
+            simd_func = core_argmin_avx2::<LAST>;
+            mod_size = 4;
+        }
+        SimdLevel::Sse41 if is_x86_feature_detected!("sse4.2") => {}
+        _ => return (!arr.is_empty()).then(|| with_value(arr, simple_argmin_tie::<_, LAST>(arr))),
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin_tie::<_, LAST>(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result = unsafe { simd_func(sim, rem.len()) };
+            Some(merge_min_tie::<_, LAST>(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin_tie::<_, LAST>(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { simd_func(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn core_argmin<const LAST: bool>(sim_arr: &[i64], rem_offset: usize) -> (i64, usize) {
+    let offset = _mm_set1_epi64x(rem_offset as i64);
+    let mut index_low = _mm_add_epi64(_mm_set_epi64x(1, 0), offset);
+
+    let increment = _mm_set1_epi64x(2);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i);
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index_low = _mm_add_epi64(new_index_low, increment);
+
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        let lt_mask = if LAST {
+            _mm_or_si128(
+                _mm_cmpgt_epi64(values_low, new_values),
+                _mm_cmpeq_epi64(values_low, new_values),
+            )
+        } else {
+            _mm_cmpgt_epi64(values_low, new_values)
+        };
+
+        values_low = _mm_or_si128(
+            _mm_and_si128(new_values, lt_mask),
+            _mm_andnot_si128(lt_mask, values_low),
+        );
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index_low, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
+        );
+    });
+
+    horizontal_argmin::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn horizontal_argmin<const LAST: bool>(
+    values_low: __m128i,
+    index_low: __m128i,
+) -> (i64, usize) {
+    let highpack = _mm_unpackhi_epi64(values_low, values_low);
+    let lowpack = _mm_unpacklo_epi64(values_low, values_low);
+    let lt_mask = _mm_cmpgt_epi64(lowpack, highpack);
+    let lowest = _mm_or_si128(
+        _mm_and_si128(highpack, lt_mask),
+        _mm_andnot_si128(lt_mask, lowpack),
+    );
+
+    let low_mask = _mm_cmpeq_epi64(lowest, values_low);
+
+    let index_low = _mm_or_si128(
+        _mm_and_si128(index_low, low_mask),
+        _mm_andnot_si128(low_mask, _mm_set1_epi64x(if LAST { -1 } else { i64::MAX })),
+    );
+
+    let value_array = std::mem::transmute::<__m128i, [i64; 2]>(values_low);
+    let index_array = std::mem::transmute::<__m128i, [i64; 2]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_avx2<const LAST: bool>(sim_arr: &[i64], rem_offset: usize) -> (i64, usize) {
+    let offset = _mm256_set1_epi64x(rem_offset as i64);
+    let mut index_low = _mm256_add_epi64(_mm256_set_epi64x(3, 2, 1, 0), offset);
+
+    let increment = _mm256_set1_epi64x(4);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i);
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_low = _mm256_add_epi64(new_index_low, increment);
+
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        let lt_mask = if LAST {
+            _mm256_or_si256(
+                _mm256_cmpgt_epi64(values_low, new_values),
+                _mm256_cmpeq_epi64(values_low, new_values),
+            )
+        } else {
+            _mm256_cmpgt_epi64(values_low, new_values)
+        };
+
+        values_low = _mm256_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, lt_mask);
+    });
+
+    horizontal_argmin_avx2::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmin_avx2<const LAST: bool>(
+    values_low: __m256i,
+    index_low: __m256i,
+) -> (i64, usize) {
+    let swapped = _mm256_permute4x64_epi64::<0b01_00_11_10>(values_low);
+    let lt_mask = _mm256_cmpgt_epi64(values_low, swapped);
+    let lowest = _mm256_blendv_epi8(values_low, swapped, lt_mask);
+
+    let swapped = _mm256_shuffle_epi32::<0b01_00_11_10>(lowest);
+    let lt_mask = _mm256_cmpgt_epi64(lowest, swapped);
+    let lowest = _mm256_blendv_epi8(lowest, swapped, lt_mask);
+
+    let low_mask = _mm256_cmpeq_epi64(lowest, values_low);
+
+    let index_low = _mm256_blendv_epi8(
+        _mm256_set1_epi64x(if LAST { -1 } else { i64::MAX }),
+        index_low,
+        low_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256i, [i64; 4]>(values_low);
+    let index_array = std::mem::transmute::<__m256i, [i64; 4]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmin_avx512<const LAST: bool>(arr: &[i64]) -> (i64, usize) {
+    let mut index_low = _mm512_setzero_si512();
+    let mut new_index_low = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi64(8);
+
+    let mut values_low = _mm512_set1_epi64(arr[0]);
+
+    arr.chunks(8).for_each(|step| {
+        let mask = (u16::MAX >> (16 - step.len())) as __mmask8;
+
+        let new_values = _mm512_maskz_loadu_epi64(mask, step.as_ptr());
+        let lt_mask = if LAST {
+            _mm512_mask_cmple_epi64_mask(mask, new_values, values_low)
+        } else {
+            _mm512_mask_cmplt_epi64_mask(mask, new_values, values_low)
+        };
+
+        values_low = _mm512_mask_blend_epi64(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi64(lt_mask, index_low, new_index_low);
+
+        new_index_low = _mm512_add_epi64(new_index_low, increment);
+    });
+
+    horizontal_argmin_avx512::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmin_avx512<const LAST: bool>(
+    values_low: __m512i,
+    index_low: __m512i,
+) -> (i64, usize) {
+    let value = _mm512_reduce_min_epi64(values_low);
+    let low_mask = _mm512_cmpeq_epi64_mask(values_low, _mm512_set1_epi64(value));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi64(low_mask, index_low)
+    } else {
+        _mm512_mask_reduce_min_epi64(low_mask, index_low)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argmax_i64(arr: &[i64]) -> Option<usize> {
+    max_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmax_i64`], but the last index is returned among equal maxima.
+pub fn argmax_i64_last(arr: &[i64]) -> Option<usize> {
+    max_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_i64(arr: &[i64]) -> Option<(i64, usize)> {
+    max_with_ties::<false>(arr)
+}
+
+fn max_with_ties<const LAST: bool>(arr: &[i64]) -> Option<(i64, usize)> {
+    let mut simd_func: unsafe fn(&[i64], usize) -> (i64, usize) = core_argmax::<LAST>;
+    let mut mod_size = 2;
+
+    match simd_level() {
+        SimdLevel::Avx512 => {
+            return (!arr.is_empty()).then(|| unsafe { core_argmax_avx512::<LAST>(arr) })
+        }
+        SimdLevel::Avx2 => {
+            simd_func = core_argmax_avx2::<LAST>;
+            mod_size = 4;
+        }
+        SimdLevel::Sse41 if is_x86_feature_detected!("sse4.2") => {}
+        _ => return (!arr.is_empty()).then(|| with_value(arr, simple_argmax_tie::<_, LAST>(arr))),
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let rem_max_index = simple_argmax_tie::<_, LAST>(rem);
+            let rem_result = (rem[rem_max_index], rem_max_index);
+            let sim_result = unsafe { simd_func(sim, rem.len()) };
+            Some(merge_max_tie::<_, LAST>(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax_tie::<_, LAST>(rem))),
+        (None, Some(sim)) => {
+            let sim_result = unsafe { simd_func(sim, 0) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn core_argmax<const LAST: bool>(sim_arr: &[i64], rem_offset: usize) -> (i64, usize) {
+    let offset = _mm_set1_epi64x(rem_offset as i64);
+    let mut index_high = _mm_add_epi64(_mm_set_epi64x(1, 0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm_set1_epi64x(2);
+
+    let mut values_high = _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i);
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index_high = _mm_add_epi64(new_index_high, increment);
+
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        let gt_mask = if LAST {
+            _mm_or_si128(
+                _mm_cmpgt_epi64(new_values, values_high),
+                _mm_cmpeq_epi64(new_values, values_high),
+            )
+        } else {
+            _mm_cmpgt_epi64(new_values, values_high)
+        };
+
+        values_high = _mm_or_si128(
+            _mm_and_si128(new_values, gt_mask),
+            _mm_andnot_si128(gt_mask, values_high),
+        );
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index_high, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
+        );
+    });
+
+    horizontal_argmax::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn horizontal_argmax<const LAST: bool>(
+    values_high: __m128i,
+    index_high: __m128i,
+) -> (i64, usize) {
+    let highpack = _mm_unpackhi_epi64(values_high, values_high);
+    let lowpack = _mm_unpacklo_epi64(values_high, values_high);
+    let gt_mask = _mm_cmpgt_epi64(highpack, lowpack);
+    let highest = _mm_or_si128(
+        _mm_and_si128(highpack, gt_mask),
+        _mm_andnot_si128(gt_mask, lowpack),
+    );
+
+    let high_mask = _mm_cmpeq_epi64(highest, values_high);
+
+    let index_high = _mm_or_si128(
+        _mm_and_si128(index_high, high_mask),
+        _mm_andnot_si128(high_mask, _mm_set1_epi64x(if LAST { -1 } else { i64::MAX })),
+    );
+
+    let value_array = std::mem::transmute::<__m128i, [i64; 2]>(values_high);
+    let index_array = std::mem::transmute::<__m128i, [i64; 2]>(index_high);
+
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(max_index);
+    let index = *index_array.get_unchecked(max_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_avx2<const LAST: bool>(sim_arr: &[i64], rem_offset: usize) -> (i64, usize) {
+    let offset = _mm256_set1_epi64x(rem_offset as i64);
+    let mut index_high = _mm256_add_epi64(_mm256_set_epi64x(3, 2, 1, 0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm256_set1_epi64x(4);
+
+    let mut values_high = _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i);
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_high = _mm256_add_epi64(new_index_high, increment);
+
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        let gt_mask = if LAST {
+            _mm256_or_si256(
+                _mm256_cmpgt_epi64(new_values, values_high),
+                _mm256_cmpeq_epi64(new_values, values_high),
+            )
+        } else {
+            _mm256_cmpgt_epi64(new_values, values_high)
+        };
+
+        values_high = _mm256_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, gt_mask);
+    });
+
+    horizontal_argmax_avx2::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmax_avx2<const LAST: bool>(
+    values_high: __m256i,
+    index_high: __m256i,
+) -> (i64, usize) {
+    let swapped = _mm256_permute4x64_epi64::<0b01_00_11_10>(values_high);
+    let gt_mask = _mm256_cmpgt_epi64(swapped, values_high);
+    let highest = _mm256_blendv_epi8(values_high, swapped, gt_mask);
+
+    let swapped = _mm256_shuffle_epi32::<0b01_00_11_10>(highest);
+    let gt_mask = _mm256_cmpgt_epi64(swapped, highest);
+    let highest = _mm256_blendv_epi8(highest, swapped, gt_mask);
+
+    let high_mask = _mm256_cmpeq_epi64(highest, values_high);
+
+    let index_high = _mm256_blendv_epi8(
+        _mm256_set1_epi64x(if LAST { -1 } else { i64::MAX }),
+        index_high,
+        high_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256i, [i64; 4]>(values_high);
+    let index_array = std::mem::transmute::<__m256i, [i64; 4]>(index_high);
+
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(max_index);
+    let index = *index_array.get_unchecked(max_index);
+
+    (value, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmax_avx512<const LAST: bool>(arr: &[i64]) -> (i64, usize) {
+    let mut index_high = _mm512_setzero_si512();
+    let mut new_index_high = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi64(8);
+
+    let mut values_high = _mm512_set1_epi64(arr[0]);
+
+    arr.chunks(8).for_each(|step| {
+        let mask = (u16::MAX >> (16 - step.len())) as __mmask8;
+
+        let new_values = _mm512_maskz_loadu_epi64(mask, step.as_ptr());
+        let gt_mask = if LAST {
+            _mm512_mask_cmpge_epi64_mask(mask, new_values, values_high)
+        } else {
+            _mm512_mask_cmpgt_epi64_mask(mask, new_values, values_high)
+        };
+
+        values_high = _mm512_mask_blend_epi64(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi64(gt_mask, index_high, new_index_high);
+
+        new_index_high = _mm512_add_epi64(new_index_high, increment);
+    });
+
+    horizontal_argmax_avx512::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmax_avx512<const LAST: bool>(
+    values_high: __m512i,
+    index_high: __m512i,
+) -> (i64, usize) {
+    let value = _mm512_reduce_max_epi64(values_high);
+    let high_mask = _mm512_cmpeq_epi64_mask(values_high, _mm512_set1_epi64(value));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi64(high_mask, index_high)
+    } else {
+        _mm512_mask_reduce_min_epi64(high_mask, index_high)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argminmax_i64(arr: &[i64]) -> Option<(usize, usize)> {
+    minmax_with_indices_i64(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_i64(arr: &[i64]) -> Option<MinMaxResult<i64>> {
+    let mut simd_func: unsafe fn(&[i64], usize) -> MinMaxResult<i64> = core_argminmax;
+    let mut mod_size = 2;
+
+    match simd_level() {
+        SimdLevel::Avx512 => {
+            return (!arr.is_empty()).then(|| unsafe { core_argminmax_avx512(arr) })
+        }
+        SimdLevel::Avx2 => {
+            simd_func = core_argminmax_avx2;
+            mod_size = 4;
+        }
+        SimdLevel::Sse41 if is_x86_feature_detected!("sse4.2") => {}
+        _ => return (!arr.is_empty()).then(|| with_values(arr, simple_argminmax(arr))),
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, rem.len()) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => {
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, 0) };
+            Some((sim_min_result, sim_max_result))
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.2")]
+unsafe fn core_argminmax(sim_arr: &[i64], rem_offset: usize) -> MinMaxResult<i64> {
+    let offset = _mm_set1_epi64x(rem_offset as i64);
+    let mut index_low = _mm_add_epi64(_mm_set_epi64x(1, 0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm_set1_epi64x(2);
+
+    let mut values_low = _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i);
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(2).skip(1).for_each(|step| {
+        new_index = _mm_add_epi64(new_index, increment);
+
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        let lt_mask = _mm_cmpgt_epi64(values_low, new_values);
+        let gt_mask = _mm_cmpgt_epi64(new_values, values_high);
+
+        values_low = _mm_or_si128(
+            _mm_and_si128(new_values, lt_mask),
+            _mm_andnot_si128(lt_mask, values_low),
+        );
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
+        );
+
+        values_high = _mm_or_si128(
+            _mm_and_si128(new_values, gt_mask),
+            _mm_andnot_si128(gt_mask, values_high),
+        );
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
+        );
+    });
+
+    (
+        horizontal_argmin::<false>(values_low, index_low),
+        horizontal_argmax::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argminmax_avx2(sim_arr: &[i64], rem_offset: usize) -> MinMaxResult<i64> {
+    let offset = _mm256_set1_epi64x(rem_offset as i64);
+    let mut index_low = _mm256_add_epi64(_mm256_set_epi64x(3, 2, 1, 0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm256_set1_epi64x(4);
+
+    let mut values_low = _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i);
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index = _mm256_add_epi64(new_index, increment);
+
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        let lt_mask = _mm256_cmpgt_epi64(values_low, new_values);
+        let gt_mask = _mm256_cmpgt_epi64(new_values, values_high);
+
+        values_low = _mm256_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index, lt_mask);
+
+        values_high = _mm256_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index, gt_mask);
+    });
+
+    (
+        horizontal_argmin_avx2::<false>(values_low, index_low),
+        horizontal_argmax_avx2::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argminmax_avx512(arr: &[i64]) -> MinMaxResult<i64> {
+    let mut index_low = _mm512_setzero_si512();
+    let mut index_high = index_low;
+    let mut new_index = _mm512_set_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi64(8);
+
+    let mut values_low = _mm512_set1_epi64(arr[0]);
+    let mut values_high = values_low;
+
+    arr.chunks(8).for_each(|step| {
+        let mask = (u16::MAX >> (16 - step.len())) as __mmask8;
+
+        let new_values = _mm512_maskz_loadu_epi64(mask, step.as_ptr());
+        let lt_mask = _mm512_mask_cmplt_epi64_mask(mask, new_values, values_low);
+        let gt_mask = _mm512_mask_cmpgt_epi64_mask(mask, new_values, values_high);
+
+        values_low = _mm512_mask_blend_epi64(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi64(lt_mask, index_low, new_index);
+
+        values_high = _mm512_mask_blend_epi64(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi64(gt_mask, index_high, new_index);
+
+        new_index = _mm512_add_epi64(new_index, increment);
+    });
+
+    (
+        horizontal_argmin_avx512::<false>(values_low, index_low),
+        horizontal_argmax_avx512::<false>(values_high, index_high),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        argmax_i64, argmax_i64_last, argmin_i64, argmin_i64_last, argminmax_i64, core_argmax,
+        core_argmax_avx2, core_argmax_avx512, core_argmin, core_argmin_avx2, core_argmin_avx512,
+        core_argminmax, core_argminmax_avx2, core_argminmax_avx512, simple_argmax, simple_argmin,
+    };
+    use crate::generic::{simple_argmax_last, simple_argmin_last};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_i64(n: usize) -> Vec<i64> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(i64::MIN, i64::MAX);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_i64(1025);
+        assert_eq!(data.len() % 8, 1);
+
+        let min_index = argmin_i64(&data).unwrap();
+        let max_index = argmax_i64(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_sse42_and_avx2_kernels_return_the_same_results() {
+        let data = get_array_i64(1024);
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        if is_x86_feature_detected!("sse4.2") {
+            assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+            assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, argmax_index);
+            let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(
+                unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx2::<false>(&data, 0) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_avx512_kernels_handle_masked_tail() {
+        if !(is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")) {
+            return;
+        }
+
+        for n in [1, 5, 8, 15, 16, 17, 1025] {
+            let data = get_array_i64(n);
+            let argmin_index = simple_argmin(&data);
+            let argmax_index = simple_argmax(&data);
+
+            assert_eq!(
+                unsafe { core_argmin_avx512::<false>(&data) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx512::<false>(&data) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let data = [
+            i64::MIN,
+            i64::MIN,
+            4,
+            6,
+            9,
+            i64::MAX,
+            22,
+            i64::MAX,
+            i64::MIN,
+            i64::MAX,
+            0,
+            1,
+            2,
+            3,
+            4,
+            5,
+            6,
+        ];
+        let argmin_index = simple_argmin(&data);
+        let argmin_simd_index = argmin_i64(&data).unwrap();
+        assert_eq!(argmin_index, argmin_simd_index);
+        assert_eq!(argmin_index, 0);
+
+        let argmax_index = simple_argmax(&data);
+        let argmax_simd_index = argmax_i64(&data).unwrap();
+        assert_eq!(argmax_index, argmax_simd_index);
+        assert_eq!(argmax_index, 5);
+
+        if is_x86_feature_detected!("avx2") {
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data[1..], 1) };
+            assert_eq!((low, high), (1, 5));
+        }
+    }
+
+    #[test]
+    fn test_last_index_is_returned_when_identical_values_found() {
+        for n in [8, 9, 1025, 4099] {
+            let data: Vec<i64> = get_array_i64(n).iter().map(|x| x % 7).collect();
+            assert_eq!(argmin_i64_last(&data), Some(simple_argmin_last(&data)));
+            assert_eq!(argmax_i64_last(&data), Some(simple_argmax_last(&data)));
+
+            let sim = &data[..n - n % 8];
+            let (min_index, max_index) = (simple_argmin_last(sim), simple_argmax_last(sim));
+            assert_eq!(unsafe { core_argmin::<true>(sim, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax::<true>(sim, 0) }.1, max_index);
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(unsafe { core_argmin_avx2::<true>(sim, 0) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx2::<true>(sim, 0) }.1, max_index);
+            }
+            if is_x86_feature_detected!("avx512f") {
+                assert_eq!(unsafe { core_argmin_avx512::<true>(sim) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx512::<true>(sim) }.1, max_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [7, 8, 9, 17, 1025] {
+            let data = get_array_i64(n);
+            let (min_index, max_index) = argminmax_i64(&data).unwrap();
+            assert_eq!(min_index, simple_argmin(&data));
+            assert_eq!(max_index, simple_argmax(&data));
+        }
+    }
+}
@@ -0,0 +1,1220 @@
+use super::{lane_mask, lane_mask_avx2};
+use crate::dispatch::{simd_level, SimdLevel};
+use crate::generic::{
+    masked_argmax_from, masked_argmin_from, simple_argmax, simple_argmax_strided, simple_argmin,
+    simple_argmin_strided, simple_argminmax, simple_max_with_index_masked,
+    simple_min_with_index_masked,
+};
+use crate::task::{
+    blocked_argmax, blocked_argmin, blocked_argminmax, blocked_gathers, blocked_masked,
+    deinterleaved_argmax, deinterleaved_argmin, find_final_index_max, find_final_index_min,
+    merge_max, merge_max_tie, merge_min, merge_min_tie, simple_argmax_tie, simple_argmin_tie,
+    split_array, split_gathers, with_value, with_values, MaskedResult, MinMaxResult,
+    I32_BLOCK_SIZE, MAX_GATHER_STRIDE,
+};
+use crate::Mask;
+use std::arch::x86_64::*;
+
+pub fn argmin_u32(arr: &[u32]) -> Option<usize> {
+    min_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmin_u32`], but the last index is returned among equal minima.
+pub fn argmin_u32_last(arr: &[u32]) -> Option<usize> {
+    min_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_u32(arr: &[u32]) -> Option<(u32, usize)> {
+    min_with_ties::<false>(arr)
+}
+
+fn min_with_ties<const LAST: bool>(arr: &[u32]) -> Option<(u32, usize)> {
+    match simd_level() {
+        SimdLevel::Scalar => {
+            return (!arr.is_empty()).then(|| with_value(arr, simple_argmin_tie::<_, LAST>(arr)))
+        }
+        SimdLevel::Avx512 => {
+            return (!arr.is_empty()).then(|| {
+                let kernel = |block: &[u32], _: usize| unsafe { core_argmin_avx512::<LAST>(block) };
+                unsafe { blocked_argmin::<_, LAST>(arr, 0, I32_BLOCK_SIZE, kernel) }
+            })
+        }
+        _ => {}
+    };
+
+    let mut simd_func: unsafe fn(&[u32], usize) -> (u32, usize) = core_argmin::<LAST>;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_avx2::<LAST>;
+        mod_size = 8;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmin_tie::<_, LAST>(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result =
+                unsafe { blocked_argmin::<_, LAST>(sim, rem.len(), I32_BLOCK_SIZE, simd_func) };
+            Some(merge_min_tie::<_, LAST>(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmin_tie::<_, LAST>(rem))),
+        (None, Some(sim)) => {
+            let sim_result =
+                unsafe { blocked_argmin::<_, LAST>(sim, 0, I32_BLOCK_SIZE, simd_func) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmin<const LAST: bool>(sim_arr: &[u32], rem_offset: usize) -> (u32, usize) {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+
+    let increment = _mm_set1_epi32(4);
+    let sign_bit = _mm_set1_epi32(i32::MIN);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm_xor_si128(
+        _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i),
+        sign_bit,
+    );
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_low = _mm_add_epi32(new_index_low, increment);
+
+        let new_values = _mm_xor_si128(_mm_loadu_si128(step.as_ptr() as *const __m128i), sign_bit);
+        let lt_mask = if LAST {
+            _mm_or_si128(
+                _mm_cmplt_epi32(new_values, values_low),
+                _mm_cmpeq_epi32(new_values, values_low),
+            )
+        } else {
+            _mm_cmplt_epi32(new_values, values_low)
+        };
+
+        values_low = _mm_or_si128(
+            _mm_and_si128(new_values, lt_mask),
+            _mm_andnot_si128(lt_mask, values_low),
+        );
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index_low, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
+        );
+    });
+
+    horizontal_argmin::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn horizontal_argmin<const LAST: bool>(
+    values_low: __m128i,
+    index_low: __m128i,
+) -> (u32, usize) {
+    let highpack = _mm_unpackhi_epi32(values_low, values_low);
+    let lowpack = _mm_unpacklo_epi32(values_low, values_low);
+    let lowest = _mm_min_epi32(highpack, lowpack);
+
+    let highpack = _mm_unpackhi_epi32(lowest, lowest);
+    let lowpack = _mm_unpacklo_epi32(lowest, lowest);
+    let lowest = _mm_min_epi32(highpack, lowpack);
+
+    let low_mask = _mm_cmpeq_epi32(lowest, values_low);
+
+    let index_low = _mm_or_si128(
+        _mm_and_si128(index_low, low_mask),
+        _mm_andnot_si128(low_mask, _mm_set1_epi32(if LAST { -1 } else { i32::MAX })),
+    );
+
+    let value_array = std::mem::transmute::<__m128i, [i32; 4]>(values_low);
+    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    ((value ^ i32::MIN) as u32, index as usize)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_avx2<const LAST: bool>(sim_arr: &[u32], rem_offset: usize) -> (u32, usize) {
+    let offset = _mm256_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm256_add_epi32(_mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0), offset);
+
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm256_xor_si256(
+        _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i),
+        sign_bit,
+    );
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_low = _mm256_add_epi32(new_index_low, increment);
+
+        let new_values = _mm256_xor_si256(
+            _mm256_loadu_si256(step.as_ptr() as *const __m256i),
+            sign_bit,
+        );
+        let lt_mask = if LAST {
+            _mm256_or_si256(
+                _mm256_cmpgt_epi32(values_low, new_values),
+                _mm256_cmpeq_epi32(values_low, new_values),
+            )
+        } else {
+            _mm256_cmpgt_epi32(values_low, new_values)
+        };
+
+        values_low = _mm256_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, lt_mask);
+    });
+
+    horizontal_argmin_avx2::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmin_avx2<const LAST: bool>(
+    values_low: __m256i,
+    index_low: __m256i,
+) -> (u32, usize) {
+    let swapped = _mm256_permute2x128_si256::<0x01>(values_low, values_low);
+    let lowest = _mm256_min_epi32(values_low, swapped);
+
+    let swapped = _mm256_shuffle_epi32::<0b01_00_11_10>(lowest);
+    let lowest = _mm256_min_epi32(lowest, swapped);
+
+    let swapped = _mm256_shuffle_epi32::<0b10_11_00_01>(lowest);
+    let lowest = _mm256_min_epi32(lowest, swapped);
+
+    let low_mask = _mm256_cmpeq_epi32(lowest, values_low);
+
+    let index_low = _mm256_blendv_epi8(
+        _mm256_set1_epi32(if LAST { -1 } else { i32::MAX }),
+        index_low,
+        low_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256i, [i32; 8]>(values_low);
+    let index_array = std::mem::transmute::<__m256i, [i32; 8]>(index_low);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    ((value ^ i32::MIN) as u32, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmin_avx512<const LAST: bool>(arr: &[u32]) -> (u32, usize) {
+    let mut index_low = _mm512_setzero_si512();
+    let mut new_index_low = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi32(16);
+
+    let mut values_low = _mm512_set1_epi32(arr[0] as i32);
+
+    arr.chunks(16).for_each(|step| {
+        let mask = (u32::MAX >> (32 - step.len())) as __mmask16;
+
+        let new_values = _mm512_maskz_loadu_epi32(mask, step.as_ptr() as *const i32);
+        let lt_mask = if LAST {
+            _mm512_mask_cmple_epu32_mask(mask, new_values, values_low)
+        } else {
+            _mm512_mask_cmplt_epu32_mask(mask, new_values, values_low)
+        };
+
+        values_low = _mm512_mask_blend_epi32(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi32(lt_mask, index_low, new_index_low);
+
+        new_index_low = _mm512_add_epi32(new_index_low, increment);
+    });
+
+    horizontal_argmin_avx512::<LAST>(values_low, index_low)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmin_avx512<const LAST: bool>(
+    values_low: __m512i,
+    index_low: __m512i,
+) -> (u32, usize) {
+    let value = _mm512_reduce_min_epu32(values_low);
+    let low_mask = _mm512_cmpeq_epi32_mask(values_low, _mm512_set1_epi32(value as i32));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi32(low_mask, index_low)
+    } else {
+        _mm512_mask_reduce_min_epi32(low_mask, index_low)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argmax_u32(arr: &[u32]) -> Option<usize> {
+    max_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmax_u32`], but the last index is returned among equal maxima.
+pub fn argmax_u32_last(arr: &[u32]) -> Option<usize> {
+    max_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_u32(arr: &[u32]) -> Option<(u32, usize)> {
+    max_with_ties::<false>(arr)
+}
+
+fn max_with_ties<const LAST: bool>(arr: &[u32]) -> Option<(u32, usize)> {
+    match simd_level() {
+        SimdLevel::Scalar => {
+            return (!arr.is_empty()).then(|| with_value(arr, simple_argmax_tie::<_, LAST>(arr)))
+        }
+        SimdLevel::Avx512 => {
+            return (!arr.is_empty()).then(|| {
+                let kernel = |block: &[u32], _: usize| unsafe { core_argmax_avx512::<LAST>(block) };
+                unsafe { blocked_argmax::<_, LAST>(arr, 0, I32_BLOCK_SIZE, kernel) }
+            })
+        }
+        _ => {}
+    };
+
+    let mut simd_func: unsafe fn(&[u32], usize) -> (u32, usize) = core_argmax::<LAST>;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_avx2::<LAST>;
+        mod_size = 8;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let rem_min_index = simple_argmax_tie::<_, LAST>(rem);
+            let rem_result = (rem[rem_min_index], rem_min_index);
+            let sim_result =
+                unsafe { blocked_argmax::<_, LAST>(sim, rem.len(), I32_BLOCK_SIZE, simd_func) };
+            Some(merge_max_tie::<_, LAST>(rem_result, sim_result))
+        }
+        (Some(rem), None) => Some(with_value(rem, simple_argmax_tie::<_, LAST>(rem))),
+        (None, Some(sim)) => {
+            let sim_result =
+                unsafe { blocked_argmax::<_, LAST>(sim, 0, I32_BLOCK_SIZE, simd_func) };
+            Some(sim_result)
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmax<const LAST: bool>(sim_arr: &[u32], rem_offset: usize) -> (u32, usize) {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_high = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm_set1_epi32(4);
+    let sign_bit = _mm_set1_epi32(i32::MIN);
+
+    let mut values_high = _mm_xor_si128(
+        _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i),
+        sign_bit,
+    );
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index_high = _mm_add_epi32(new_index_high, increment);
+
+        let new_values = _mm_xor_si128(_mm_loadu_si128(step.as_ptr() as *const __m128i), sign_bit);
+        let gt_mask = if LAST {
+            _mm_or_si128(
+                _mm_cmpgt_epi32(new_values, values_high),
+                _mm_cmpeq_epi32(new_values, values_high),
+            )
+        } else {
+            _mm_cmpgt_epi32(new_values, values_high)
+        };
+
+        values_high = _mm_or_si128(
+            _mm_and_si128(new_values, gt_mask),
+            _mm_andnot_si128(gt_mask, values_high),
+        );
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index_high, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
+        );
+    });
+
+    horizontal_argmax::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn horizontal_argmax<const LAST: bool>(
+    values_high: __m128i,
+    index_high: __m128i,
+) -> (u32, usize) {
+    let highpack = _mm_unpackhi_epi32(values_high, values_high);
+    let lowpack = _mm_unpacklo_epi32(values_high, values_high);
+    let highest = _mm_max_epi32(highpack, lowpack);
+
+    let highpack = _mm_unpackhi_epi32(highest, highest);
+    let lowpack = _mm_unpacklo_epi32(highest, highest);
+    let highest = _mm_max_epi32(highpack, lowpack);
+
+    let high_mask = _mm_cmpeq_epi32(highest, values_high);
+
+    let index_high = _mm_or_si128(
+        _mm_and_si128(index_high, high_mask),
+        _mm_andnot_si128(high_mask, _mm_set1_epi32(if LAST { -1 } else { i32::MAX })),
+    );
+
+    let value_array = std::mem::transmute::<__m128i, [i32; 4]>(values_high);
+    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_high);
+
+    let min_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(min_index);
+    let index = *index_array.get_unchecked(min_index);
+
+    ((value ^ i32::MIN) as u32, index as usize)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_avx2<const LAST: bool>(sim_arr: &[u32], rem_offset: usize) -> (u32, usize) {
+    let offset = _mm256_set1_epi32(rem_offset as i32);
+    let mut index_high = _mm256_add_epi32(_mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0), offset);
+    let mut new_index_high = index_high;
+
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+
+    let mut values_high = _mm256_xor_si256(
+        _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i),
+        sign_bit,
+    );
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index_high = _mm256_add_epi32(new_index_high, increment);
+
+        let new_values = _mm256_xor_si256(
+            _mm256_loadu_si256(step.as_ptr() as *const __m256i),
+            sign_bit,
+        );
+        let gt_mask = if LAST {
+            _mm256_or_si256(
+                _mm256_cmpgt_epi32(new_values, values_high),
+                _mm256_cmpeq_epi32(new_values, values_high),
+            )
+        } else {
+            _mm256_cmpgt_epi32(new_values, values_high)
+        };
+
+        values_high = _mm256_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, gt_mask);
+    });
+
+    horizontal_argmax_avx2::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn horizontal_argmax_avx2<const LAST: bool>(
+    values_high: __m256i,
+    index_high: __m256i,
+) -> (u32, usize) {
+    let swapped = _mm256_permute2x128_si256::<0x01>(values_high, values_high);
+    let highest = _mm256_max_epi32(values_high, swapped);
+
+    let swapped = _mm256_shuffle_epi32::<0b01_00_11_10>(highest);
+    let highest = _mm256_max_epi32(highest, swapped);
+
+    let swapped = _mm256_shuffle_epi32::<0b10_11_00_01>(highest);
+    let highest = _mm256_max_epi32(highest, swapped);
+
+    let high_mask = _mm256_cmpeq_epi32(highest, values_high);
+
+    let index_high = _mm256_blendv_epi8(
+        _mm256_set1_epi32(if LAST { -1 } else { i32::MAX }),
+        index_high,
+        high_mask,
+    );
+
+    let value_array = std::mem::transmute::<__m256i, [i32; 8]>(values_high);
+    let index_array = std::mem::transmute::<__m256i, [i32; 8]>(index_high);
+
+    let max_index = if LAST {
+        simple_argmax(&index_array)
+    } else {
+        simple_argmin(&index_array)
+    };
+    let value = *value_array.get_unchecked(max_index);
+    let index = *index_array.get_unchecked(max_index);
+
+    ((value ^ i32::MIN) as u32, index as usize)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argmax_avx512<const LAST: bool>(arr: &[u32]) -> (u32, usize) {
+    let mut index_high = _mm512_setzero_si512();
+    let mut new_index_high = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi32(16);
+
+    let mut values_high = _mm512_set1_epi32(arr[0] as i32);
+
+    arr.chunks(16).for_each(|step| {
+        let mask = (u32::MAX >> (32 - step.len())) as __mmask16;
+
+        let new_values = _mm512_maskz_loadu_epi32(mask, step.as_ptr() as *const i32);
+        let gt_mask = if LAST {
+            _mm512_mask_cmpge_epu32_mask(mask, new_values, values_high)
+        } else {
+            _mm512_mask_cmpgt_epu32_mask(mask, new_values, values_high)
+        };
+
+        values_high = _mm512_mask_blend_epi32(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi32(gt_mask, index_high, new_index_high);
+
+        new_index_high = _mm512_add_epi32(new_index_high, increment);
+    });
+
+    horizontal_argmax_avx512::<LAST>(values_high, index_high)
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn horizontal_argmax_avx512<const LAST: bool>(
+    values_high: __m512i,
+    index_high: __m512i,
+) -> (u32, usize) {
+    let value = _mm512_reduce_max_epu32(values_high);
+    let high_mask = _mm512_cmpeq_epi32_mask(values_high, _mm512_set1_epi32(value as i32));
+    let index = if LAST {
+        _mm512_mask_reduce_max_epi32(high_mask, index_high)
+    } else {
+        _mm512_mask_reduce_min_epi32(high_mask, index_high)
+    };
+
+    (value, index as usize)
+}
+
+pub fn argminmax_u32(arr: &[u32]) -> Option<(usize, usize)> {
+    minmax_with_indices_u32(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_u32(arr: &[u32]) -> Option<MinMaxResult<u32>> {
+    match simd_level() {
+        SimdLevel::Scalar => {
+            return (!arr.is_empty()).then(|| with_values(arr, simple_argminmax(arr)))
+        }
+        SimdLevel::Avx512 => {
+            return (!arr.is_empty()).then(|| unsafe {
+                blocked_argminmax(arr, 0, I32_BLOCK_SIZE, |block, _| {
+                    core_argminmax_avx512(block)
+                })
+            })
+        }
+        _ => {}
+    };
+
+    let mut simd_func: unsafe fn(&[u32], usize) -> MinMaxResult<u32> = core_argminmax;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argminmax_avx2;
+        mod_size = 8;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) =
+                unsafe { blocked_argminmax(sim, rem.len(), I32_BLOCK_SIZE, simd_func) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => {
+            let (sim_min_result, sim_max_result) =
+                unsafe { blocked_argminmax(sim, 0, I32_BLOCK_SIZE, simd_func) };
+            Some((sim_min_result, sim_max_result))
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argminmax(sim_arr: &[u32], rem_offset: usize) -> MinMaxResult<u32> {
+    let offset = _mm_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm_set1_epi32(4);
+    let sign_bit = _mm_set1_epi32(i32::MIN);
+
+    let mut values_low = _mm_xor_si128(
+        _mm_loadu_si128(sim_arr.as_ptr() as *const __m128i),
+        sign_bit,
+    );
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
+        new_index = _mm_add_epi32(new_index, increment);
+
+        let new_values = _mm_xor_si128(_mm_loadu_si128(step.as_ptr() as *const __m128i), sign_bit);
+        let lt_mask = _mm_cmplt_epi32(new_values, values_low);
+        let gt_mask = _mm_cmpgt_epi32(new_values, values_high);
+
+        values_low = _mm_or_si128(
+            _mm_and_si128(new_values, lt_mask),
+            _mm_andnot_si128(lt_mask, values_low),
+        );
+        index_low = _mm_or_si128(
+            _mm_and_si128(new_index, lt_mask),
+            _mm_andnot_si128(lt_mask, index_low),
+        );
+
+        values_high = _mm_or_si128(
+            _mm_and_si128(new_values, gt_mask),
+            _mm_andnot_si128(gt_mask, values_high),
+        );
+        index_high = _mm_or_si128(
+            _mm_and_si128(new_index, gt_mask),
+            _mm_andnot_si128(gt_mask, index_high),
+        );
+    });
+
+    (
+        horizontal_argmin::<false>(values_low, index_low),
+        horizontal_argmax::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argminmax_avx2(sim_arr: &[u32], rem_offset: usize) -> MinMaxResult<u32> {
+    let offset = _mm256_set1_epi32(rem_offset as i32);
+    let mut index_low = _mm256_add_epi32(_mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0), offset);
+    let mut index_high = index_low;
+    let mut new_index = index_low;
+
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+
+    let mut values_low = _mm256_xor_si256(
+        _mm256_loadu_si256(sim_arr.as_ptr() as *const __m256i),
+        sign_bit,
+    );
+    let mut values_high = values_low;
+
+    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
+        new_index = _mm256_add_epi32(new_index, increment);
+
+        let new_values = _mm256_xor_si256(
+            _mm256_loadu_si256(step.as_ptr() as *const __m256i),
+            sign_bit,
+        );
+        let lt_mask = _mm256_cmpgt_epi32(values_low, new_values);
+        let gt_mask = _mm256_cmpgt_epi32(new_values, values_high);
+
+        values_low = _mm256_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index, lt_mask);
+
+        values_high = _mm256_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index, gt_mask);
+    });
+
+    (
+        horizontal_argmin_avx2::<false>(values_low, index_low),
+        horizontal_argmax_avx2::<false>(values_high, index_high),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+unsafe fn core_argminmax_avx512(arr: &[u32]) -> MinMaxResult<u32> {
+    let mut index_low = _mm512_setzero_si512();
+    let mut index_high = index_low;
+    let mut new_index = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm512_set1_epi32(16);
+
+    let mut values_low = _mm512_set1_epi32(arr[0] as i32);
+    let mut values_high = values_low;
+
+    arr.chunks(16).for_each(|step| {
+        let mask = (u32::MAX >> (32 - step.len())) as __mmask16;
+
+        let new_values = _mm512_maskz_loadu_epi32(mask, step.as_ptr() as *const i32);
+        let lt_mask = _mm512_mask_cmplt_epu32_mask(mask, new_values, values_low);
+        let gt_mask = _mm512_mask_cmpgt_epu32_mask(mask, new_values, values_high);
+
+        values_low = _mm512_mask_blend_epi32(lt_mask, values_low, new_values);
+        index_low = _mm512_mask_blend_epi32(lt_mask, index_low, new_index);
+
+        values_high = _mm512_mask_blend_epi32(gt_mask, values_high, new_values);
+        index_high = _mm512_mask_blend_epi32(gt_mask, index_high, new_index);
+
+        new_index = _mm512_add_epi32(new_index, increment);
+    });
+
+    (
+        horizontal_argmin_avx512::<false>(values_low, index_low),
+        horizontal_argmax_avx512::<false>(values_high, index_high),
+    )
+}
+
+/// Argmin of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ... counted in strided
+/// elements. Strides above 4 are read with AVX2 gathers, the others are copied
+/// out a block at a time and handed to [`argmin_u32`].
+pub fn argmin_u32_strided(arr: &[u32], stride: usize) -> Option<usize> {
+    assert!(stride > 0, "stride must be non-zero");
+
+    if stride <= 4 || stride > MAX_GATHER_STRIDE || simd_level() < SimdLevel::Avx2 {
+        return deinterleaved_argmin(arr, stride, argmin_u32);
+    }
+
+    match split_gathers(arr, stride) {
+        (0 | 1, _) => deinterleaved_argmin(arr, stride, argmin_u32),
+        (gathers, rem) => {
+            let sim_result = unsafe {
+                blocked_gathers(
+                    arr,
+                    stride,
+                    gathers,
+                    I32_BLOCK_SIZE,
+                    core_argmin_gather_avx2,
+                    merge_min,
+                )
+            };
+            if rem.is_empty() {
+                return Some(sim_result.1);
+            }
+            let rem_min_index = simple_argmin_strided(rem, stride);
+            let rem_result = (rem[rem_min_index * stride], gathers * 8 + rem_min_index);
+            find_final_index_min(rem_result, sim_result)
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_gather_avx2(arr: &[u32], stride: usize, gathers: usize) -> (u32, usize) {
+    let offsets = _mm256_mullo_epi32(
+        _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0),
+        _mm256_set1_epi32(stride as i32),
+    );
+    let mut index_low = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+    let mut new_index_low = index_low;
+
+    let mut values_low = _mm256_xor_si256(
+        _mm256_i32gather_epi32::<4>(arr.as_ptr() as *const i32, offsets),
+        sign_bit,
+    );
+
+    (1..gathers).for_each(|gather| {
+        new_index_low = _mm256_add_epi32(new_index_low, increment);
+
+        let step = arr.as_ptr().add(gather * 8 * stride);
+        let new_values = _mm256_xor_si256(
+            _mm256_i32gather_epi32::<4>(step as *const i32, offsets),
+            sign_bit,
+        );
+        let lt_mask = _mm256_cmpgt_epi32(values_low, new_values);
+
+        values_low = _mm256_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, lt_mask);
+    });
+
+    horizontal_argmin_avx2::<false>(values_low, index_low)
+}
+
+/// Argmax of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ... counted in strided
+/// elements, see [`argmin_u32_strided`].
+pub fn argmax_u32_strided(arr: &[u32], stride: usize) -> Option<usize> {
+    assert!(stride > 0, "stride must be non-zero");
+
+    if stride <= 4 || stride > MAX_GATHER_STRIDE || simd_level() < SimdLevel::Avx2 {
+        return deinterleaved_argmax(arr, stride, argmax_u32);
+    }
+
+    match split_gathers(arr, stride) {
+        (0 | 1, _) => deinterleaved_argmax(arr, stride, argmax_u32),
+        (gathers, rem) => {
+            let sim_result = unsafe {
+                blocked_gathers(
+                    arr,
+                    stride,
+                    gathers,
+                    I32_BLOCK_SIZE,
+                    core_argmax_gather_avx2,
+                    merge_max,
+                )
+            };
+            if rem.is_empty() {
+                return Some(sim_result.1);
+            }
+            let rem_max_index = simple_argmax_strided(rem, stride);
+            let rem_result = (rem[rem_max_index * stride], gathers * 8 + rem_max_index);
+            find_final_index_max(rem_result, sim_result)
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_gather_avx2(arr: &[u32], stride: usize, gathers: usize) -> (u32, usize) {
+    let offsets = _mm256_mullo_epi32(
+        _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0),
+        _mm256_set1_epi32(stride as i32),
+    );
+    let mut index_high = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+    let mut new_index_high = index_high;
+
+    let mut values_high = _mm256_xor_si256(
+        _mm256_i32gather_epi32::<4>(arr.as_ptr() as *const i32, offsets),
+        sign_bit,
+    );
+
+    (1..gathers).for_each(|gather| {
+        new_index_high = _mm256_add_epi32(new_index_high, increment);
+
+        let step = arr.as_ptr().add(gather * 8 * stride);
+        let new_values = _mm256_xor_si256(
+            _mm256_i32gather_epi32::<4>(step as *const i32, offsets),
+            sign_bit,
+        );
+        let gt_mask = _mm256_cmpgt_epi32(new_values, values_high);
+
+        values_high = _mm256_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, gt_mask);
+    });
+
+    horizontal_argmax_avx2::<false>(values_high, index_high)
+}
+
+/// Argmin of the elements of `arr` selected by `mask`, or `None` when nothing
+/// is selected. The selection becomes a lane mask that is ANDed into the
+/// compare, so the input is still scanned once with SIMD loads.
+pub fn argmin_u32_masked(arr: &[u32], mask: Mask) -> Option<usize> {
+    min_with_index_u32_masked(arr, mask).map(|(_, index)| index)
+}
+
+/// The smallest element selected by `mask` together with its first index.
+pub fn min_with_index_u32_masked(arr: &[u32], mask: Mask) -> Option<(u32, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return simple_min_with_index_masked(arr, mask);
+    }
+    mask.check_len(arr.len());
+
+    let mut simd_func: unsafe fn(&[u32], Mask, usize) -> MaskedResult<u32> = core_argmin_masked;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_masked_avx2;
+        mod_size = 8;
+    };
+
+    let (sim, rem) = arr.split_at(arr.len() - arr.len() % mod_size);
+    let sim_result = unsafe { blocked_masked(sim, mask, I32_BLOCK_SIZE, simd_func, merge_min) };
+    match (masked_argmin_from(rem, mask, sim.len()), sim_result) {
+        (Some(rem_result), Some(sim_result)) => Some(merge_min(rem_result, sim_result)),
+        (rem_result, sim_result) => rem_result.or(sim_result),
+    }
+}
+
+/// Lanes the mask leaves out keep their previous value and index. `seen` marks
+/// the lanes that have taken a selected value, and the first selected value of
+/// a lane is taken whatever it compares like. `offset` is the position of
+/// `sim_arr` in `mask`.
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmin_masked(sim_arr: &[u32], mask: Mask, offset: usize) -> MaskedResult<u32> {
+    let lane_bits = _mm_set_epi32(8, 4, 2, 1);
+    let mut new_index_low = _mm_set_epi32(3, 2, 1, 0);
+    let increment = _mm_set1_epi32(4);
+    let sign_bit = _mm_set1_epi32(i32::MIN);
+
+    let mut values_low = _mm_set1_epi32(i32::MAX);
+    let mut index_low = _mm_set1_epi32(i32::MAX);
+    let mut seen = _mm_setzero_si128();
+
+    for (i, step) in sim_arr.chunks_exact(4).enumerate() {
+        let selected = lane_mask(mask.lanes(offset + i * 4, 4), lane_bits);
+
+        let new_values = _mm_xor_si128(_mm_loadu_si128(step.as_ptr() as *const __m128i), sign_bit);
+        let lt_mask = _mm_cmplt_epi32(new_values, values_low);
+        let lt_mask = _mm_or_si128(
+            _mm_and_si128(lt_mask, selected),
+            _mm_andnot_si128(seen, selected),
+        );
+
+        values_low = _mm_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm_blendv_epi8(index_low, new_index_low, lt_mask);
+        seen = _mm_or_si128(seen, selected);
+        new_index_low = _mm_add_epi32(new_index_low, increment);
+    }
+
+    (_mm_movemask_epi8(seen) != 0).then(|| horizontal_argmin::<false>(values_low, index_low))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_masked_avx2(sim_arr: &[u32], mask: Mask, offset: usize) -> MaskedResult<u32> {
+    let lane_bits = _mm256_set_epi32(128, 64, 32, 16, 8, 4, 2, 1);
+    let mut new_index_low = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+
+    let mut values_low = _mm256_set1_epi32(i32::MAX);
+    let mut index_low = _mm256_set1_epi32(i32::MAX);
+    let mut seen = _mm256_setzero_si256();
+
+    for (i, step) in sim_arr.chunks_exact(8).enumerate() {
+        let selected = lane_mask_avx2(mask.lanes(offset + i * 8, 8), lane_bits);
+
+        let new_values = _mm256_xor_si256(
+            _mm256_loadu_si256(step.as_ptr() as *const __m256i),
+            sign_bit,
+        );
+        let lt_mask = _mm256_cmpgt_epi32(values_low, new_values);
+        let lt_mask = _mm256_or_si256(
+            _mm256_and_si256(lt_mask, selected),
+            _mm256_andnot_si256(seen, selected),
+        );
+
+        values_low = _mm256_blendv_epi8(values_low, new_values, lt_mask);
+        index_low = _mm256_blendv_epi8(index_low, new_index_low, lt_mask);
+        seen = _mm256_or_si256(seen, selected);
+        new_index_low = _mm256_add_epi32(new_index_low, increment);
+    }
+
+    (_mm256_movemask_epi8(seen) != 0)
+        .then(|| horizontal_argmin_avx2::<false>(values_low, index_low))
+}
+
+/// Argmax of the elements of `arr` selected by `mask`, see [`argmin_u32_masked`].
+pub fn argmax_u32_masked(arr: &[u32], mask: Mask) -> Option<usize> {
+    max_with_index_u32_masked(arr, mask).map(|(_, index)| index)
+}
+
+/// The largest element selected by `mask` together with its first index.
+pub fn max_with_index_u32_masked(arr: &[u32], mask: Mask) -> Option<(u32, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return simple_max_with_index_masked(arr, mask);
+    }
+    mask.check_len(arr.len());
+
+    let mut simd_func: unsafe fn(&[u32], Mask, usize) -> MaskedResult<u32> = core_argmax_masked;
+    let mut mod_size = 4;
+
+    if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_masked_avx2;
+        mod_size = 8;
+    };
+
+    let (sim, rem) = arr.split_at(arr.len() - arr.len() % mod_size);
+    let sim_result = unsafe { blocked_masked(sim, mask, I32_BLOCK_SIZE, simd_func, merge_max) };
+    match (masked_argmax_from(rem, mask, sim.len()), sim_result) {
+        (Some(rem_result), Some(sim_result)) => Some(merge_max(rem_result, sim_result)),
+        (rem_result, sim_result) => rem_result.or(sim_result),
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmax_masked(sim_arr: &[u32], mask: Mask, offset: usize) -> MaskedResult<u32> {
+    let lane_bits = _mm_set_epi32(8, 4, 2, 1);
+    let mut new_index_high = _mm_set_epi32(3, 2, 1, 0);
+    let increment = _mm_set1_epi32(4);
+    let sign_bit = _mm_set1_epi32(i32::MIN);
+
+    let mut values_high = _mm_set1_epi32(i32::MIN);
+    let mut index_high = _mm_set1_epi32(i32::MAX);
+    let mut seen = _mm_setzero_si128();
+
+    for (i, step) in sim_arr.chunks_exact(4).enumerate() {
+        let selected = lane_mask(mask.lanes(offset + i * 4, 4), lane_bits);
+
+        let new_values = _mm_xor_si128(_mm_loadu_si128(step.as_ptr() as *const __m128i), sign_bit);
+        let gt_mask = _mm_cmpgt_epi32(new_values, values_high);
+        let gt_mask = _mm_or_si128(
+            _mm_and_si128(gt_mask, selected),
+            _mm_andnot_si128(seen, selected),
+        );
+
+        values_high = _mm_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm_blendv_epi8(index_high, new_index_high, gt_mask);
+        seen = _mm_or_si128(seen, selected);
+        new_index_high = _mm_add_epi32(new_index_high, increment);
+    }
+
+    (_mm_movemask_epi8(seen) != 0).then(|| horizontal_argmax::<false>(values_high, index_high))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_masked_avx2(sim_arr: &[u32], mask: Mask, offset: usize) -> MaskedResult<u32> {
+    let lane_bits = _mm256_set_epi32(128, 64, 32, 16, 8, 4, 2, 1);
+    let mut new_index_high = _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0);
+    let increment = _mm256_set1_epi32(8);
+    let sign_bit = _mm256_set1_epi32(i32::MIN);
+
+    let mut values_high = _mm256_set1_epi32(i32::MIN);
+    let mut index_high = _mm256_set1_epi32(i32::MAX);
+    let mut seen = _mm256_setzero_si256();
+
+    for (i, step) in sim_arr.chunks_exact(8).enumerate() {
+        let selected = lane_mask_avx2(mask.lanes(offset + i * 8, 8), lane_bits);
+
+        let new_values = _mm256_xor_si256(
+            _mm256_loadu_si256(step.as_ptr() as *const __m256i),
+            sign_bit,
+        );
+        let gt_mask = _mm256_cmpgt_epi32(new_values, values_high);
+        let gt_mask = _mm256_or_si256(
+            _mm256_and_si256(gt_mask, selected),
+            _mm256_andnot_si256(seen, selected),
+        );
+
+        values_high = _mm256_blendv_epi8(values_high, new_values, gt_mask);
+        index_high = _mm256_blendv_epi8(index_high, new_index_high, gt_mask);
+        seen = _mm256_or_si256(seen, selected);
+        new_index_high = _mm256_add_epi32(new_index_high, increment);
+    }
+
+    (_mm256_movemask_epi8(seen) != 0)
+        .then(|| horizontal_argmax_avx2::<false>(values_high, index_high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        argmax_u32, argmax_u32_last, argmax_u32_masked, argmax_u32_strided, argmin_u32,
+        argmin_u32_last, argmin_u32_masked, argmin_u32_strided, argminmax_u32, core_argmax,
+        core_argmax_avx2, core_argmax_avx512, core_argmax_gather_avx2, core_argmax_masked,
+        core_argmax_masked_avx2, core_argmin, core_argmin_avx2, core_argmin_avx512,
+        core_argmin_gather_avx2, core_argmin_masked, core_argmin_masked_avx2, core_argminmax,
+        core_argminmax_avx2, core_argminmax_avx512, simple_argmax, simple_argmax_strided,
+        simple_argmin, simple_argmin_strided, split_gathers,
+    };
+    use crate::generic::{simple_argmax_last, simple_argmin_last};
+    use crate::simd::{check_masked_kernels, MaskedKernel};
+    use crate::task::{blocked_gathers, merge_max, merge_min};
+    use rand::{thread_rng, Rng};
+    use rand_distr::Uniform;
+
+    fn get_array_u32(n: usize) -> Vec<u32> {
+        let rng = thread_rng();
+        let uni = Uniform::new_inclusive(u32::MIN, u32::MAX);
+        rng.sample_iter(uni).take(n).collect()
+    }
+
+    #[test]
+    fn test_both_versions_return_the_same_results() {
+        let data = get_array_u32(1025);
+        assert_eq!(data.len() % 4, 1);
+
+        let min_index = argmin_u32(&data).unwrap();
+        let max_index = argmax_u32(&data).unwrap();
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        assert_eq!(argmin_index, min_index);
+        assert_eq!(argmax_index, max_index);
+    }
+
+    #[test]
+    fn test_sse_and_avx2_kernels_return_the_same_results() {
+        let data = get_array_u32(1024);
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        if is_x86_feature_detected!("sse4.1") {
+            assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+            assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, argmax_index);
+            let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(
+                unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx2::<false>(&data, 0) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_avx512_kernels_handle_masked_tail() {
+        if !(is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")) {
+            return;
+        }
+
+        for n in [1, 5, 8, 15, 16, 17, 1025] {
+            let data = get_array_u32(n);
+            let argmin_index = simple_argmin(&data);
+            let argmax_index = simple_argmax(&data);
+
+            assert_eq!(
+                unsafe { core_argmin_avx512::<false>(&data) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx512::<false>(&data) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_values_above_i32_max_are_ordered_as_unsigned() {
+        let data = [
+            1 << 31,
+            3,
+            u32::MAX - 1,
+            i32::MAX as u32,
+            u32::MAX,
+            0,
+            (1 << 31) + 1,
+            7,
+            42,
+        ];
+        assert_eq!(argmin_u32(&data), Some(5));
+        assert_eq!(argmax_u32(&data), Some(4));
+        assert_eq!(argminmax_u32(&data), Some((5, 4)));
+    }
+
+    #[test]
+    fn test_gather_kernels_match_scalar_strided() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+
+        for stride in [5, 7, 16, 33] {
+            for n in [16 * stride, 16 * stride + 1, 1000 * stride - 3] {
+                let data = get_array_u32(n);
+                let (gathers, rem) = split_gathers(&data, stride);
+                let body = &data[..data.len() - rem.len()];
+
+                let (_, min_index) = unsafe { core_argmin_gather_avx2(body, stride, gathers) };
+                let (_, max_index) = unsafe { core_argmax_gather_avx2(body, stride, gathers) };
+                assert_eq!(min_index, simple_argmin_strided(body, stride));
+                assert_eq!(max_index, simple_argmax_strided(body, stride));
+
+                let (_, min_index) = unsafe {
+                    blocked_gathers(
+                        body,
+                        stride,
+                        gathers,
+                        16,
+                        core_argmin_gather_avx2,
+                        merge_min,
+                    )
+                };
+                let (_, max_index) = unsafe {
+                    blocked_gathers(
+                        body,
+                        stride,
+                        gathers,
+                        16,
+                        core_argmax_gather_avx2,
+                        merge_max,
+                    )
+                };
+                assert_eq!(min_index, simple_argmin_strided(body, stride));
+                assert_eq!(max_index, simple_argmax_strided(body, stride));
+
+                assert_eq!(
+                    argmin_u32_strided(&data, stride),
+                    Some(simple_argmin_strided(&data, stride))
+                );
+                assert_eq!(
+                    argmax_u32_strided(&data, stride),
+                    Some(simple_argmax_strided(&data, stride))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_masked_kernels_match_scalar_masked() {
+        let mut kernels: Vec<(usize, MaskedKernel<u32>, MaskedKernel<u32>)> =
+            vec![(4, core_argmin_masked, core_argmax_masked)];
+        if is_x86_feature_detected!("avx2") {
+            kernels.push((8, core_argmin_masked_avx2, core_argmax_masked_avx2));
+        }
+        check_masked_kernels(
+            get_array_u32,
+            |x| x % 7,
+            &kernels,
+            argmin_u32_masked,
+            argmax_u32_masked,
+        );
+    }
+
+    #[test]
+    fn test_first_index_is_returned_when_identical_values_found() {
+        let data = [10, u32::MIN, 6, 9, 9, 22, u32::MAX, 4, u32::MAX];
+        let argmin_index = simple_argmin(&data);
+        let argmin_simd_index = argmin_u32(&data).unwrap();
+        assert_eq!(argmin_index, argmin_simd_index);
+        assert_eq!(argmin_index, 1);
+
+        let argmax_index = simple_argmax(&data);
+        let argmax_simd_index = argmax_u32(&data).unwrap();
+        assert_eq!(argmax_index, argmax_simd_index);
+        assert_eq!(argmax_index, 6);
+    }
+
+    #[test]
+    fn test_last_index_is_returned_when_identical_values_found() {
+        for n in [8, 9, 1025, 4099] {
+            let data: Vec<u32> = get_array_u32(n).iter().map(|x| x % 7).collect();
+            assert_eq!(argmin_u32_last(&data), Some(simple_argmin_last(&data)));
+            assert_eq!(argmax_u32_last(&data), Some(simple_argmax_last(&data)));
+
+            let sim = &data[..n - n % 8];
+            let (min_index, max_index) = (simple_argmin_last(sim), simple_argmax_last(sim));
+            assert_eq!(unsafe { core_argmin::<true>(sim, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax::<true>(sim, 0) }.1, max_index);
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(unsafe { core_argmin_avx2::<true>(sim, 0) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx2::<true>(sim, 0) }.1, max_index);
+            }
+            if is_x86_feature_detected!("avx512f") {
+                assert_eq!(unsafe { core_argmin_avx512::<true>(sim) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx512::<true>(sim) }.1, max_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [7, 8, 9, 1025] {
+            let data = get_array_u32(n);
+            let (min_index, max_index) = argminmax_u32(&data).unwrap();
+            assert_eq!(min_index, simple_argmin(&data));
+            assert_eq!(max_index, simple_argmax(&data));
+        }
+    }
+}
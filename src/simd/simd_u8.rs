@@ -1,337 +1,664 @@
-use crate::generic::{simple_argmax, simple_argmin};
-use crate::task::{find_final_index_max, find_final_index_min, split_array};
+use crate::dispatch::{simd_level, SimdLevel};
+use crate::generic::simple_argminmax;
+use crate::task::{
+    merge_max, merge_max_tie, merge_min, merge_min_tie, simple_argmax_tie, simple_argmin_tie,
+    split_array, with_value, with_values, MinMaxResult,
+};
 use std::arch::x86_64::*;
 
+/// Number of bytes the SSE and AVX2 kernels reduce to a single value before
+/// comparing it with the running result. Only the value is tracked while
+/// scanning, the position is found afterwards by rescanning the winning block.
+const BLOCK_SIZE: usize = 1024;
+
+/// Blocks of `sim_arr` with their position, from the last one when `LAST`, so
+/// that the first block reaching the extreme is the one holding its index.
+fn blocks<const LAST: bool>(sim_arr: &[u8]) -> impl Iterator<Item = (usize, &[u8])> + '_ {
+    let count = sim_arr.len().div_ceil(BLOCK_SIZE);
+
+    (0..count).map(move |n| {
+        let i = if LAST { count - 1 - n } else { n };
+        let end = sim_arr.len().min((i + 1) * BLOCK_SIZE);
+        (i, &sim_arr[i * BLOCK_SIZE..end])
+    })
+}
+
 pub fn argmin_u8(arr: &[u8]) -> Option<usize> {
-    let n = arr.len();
-    let mut simd_func: unsafe fn(&[u8], usize) -> (u8, usize) = core_argmin;
-    let mut mod_size = 4;
+    min_with_ties::<false>(arr).map(|(_, index)| index)
+}
 
-    if (17..std::i16::MAX).contains(&(n as i16)) {
-        simd_func = core_argmin_ext;
-        mod_size = 8;
+/// Like [`argmin_u8`], but the last index is returned among equal minima.
+pub fn argmin_u8_last(arr: &[u8]) -> Option<usize> {
+    min_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The smallest value of `arr` together with its first index.
+pub fn min_with_index_u8(arr: &[u8]) -> Option<(u8, usize)> {
+    min_with_ties::<false>(arr)
+}
+
+fn min_with_ties<const LAST: bool>(arr: &[u8]) -> Option<(u8, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return (!arr.is_empty()).then(|| with_value(arr, simple_argmin_tie::<_, LAST>(arr)));
+    }
+
+    let mut simd_func: unsafe fn(&[u8], usize) -> (u8, usize) = core_argmin::<LAST>;
+    let mut mod_size = 16;
+
+    if simd_level() == SimdLevel::Avx512 {
+        simd_func = core_argmin_avx512::<LAST>;
+        mod_size = 64;
+    } else if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmin_avx2::<LAST>;
+        mod_size = 32;
     };
 
     match split_array(arr, mod_size) {
         (Some(rem), Some(sim)) => {
-            let rem_min_index = simple_argmin(rem);
+            let rem_min_index = simple_argmin_tie::<_, LAST>(rem);
             let rem_result = (rem[rem_min_index], rem_min_index);
             let sim_result = unsafe { simd_func(sim, rem.len()) };
-            find_final_index_min(rem_result, sim_result)
+            Some(merge_min_tie::<_, LAST>(rem_result, sim_result))
         }
-        (Some(rem), None) => Some(simple_argmin(rem)),
+        (Some(rem), None) => Some(with_value(rem, simple_argmin_tie::<_, LAST>(rem))),
         (None, Some(sim)) => {
             let sim_result = unsafe { simd_func(sim, 0) };
-            Some(sim_result.1)
+            Some(sim_result)
         }
         (None, None) => None,
     }
 }
 
-unsafe fn core_argmin(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
-    let offset = _mm_set1_epi32(rem_offset as i32);
-    let mut index_low = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmin<const LAST: bool>(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
+    let mut blocks = blocks::<LAST>(sim_arr);
+    let (mut low_block, block) = blocks.next().unwrap();
+    let mut low = block_min(block);
 
-    let increment = _mm_set1_epi32(4);
-    let mut new_index_low = index_low;
+    for (i, block) in blocks {
+        if low == u8::MIN {
+            break;
+        }
+        let block_low = block_min(block);
+        if block_low < low {
+            low = block_low;
+            low_block = i;
+        }
+    }
 
-    let mut values_low = _mm_set_epi32(
-        sim_arr[3] as i32,
-        sim_arr[2] as i32,
-        sim_arr[1] as i32,
-        sim_arr[0] as i32,
-    );
+    let block_offset = low_block * BLOCK_SIZE;
+    let block = &sim_arr[block_offset..sim_arr.len().min(block_offset + BLOCK_SIZE)];
+    let index = block_offset + find_index::<LAST>(block, low);
 
-    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
-        new_index_low = _mm_add_epi32(new_index_low, increment);
-
-        let new_values = _mm_set_epi32(
-            step[3] as i32,
-            step[2] as i32,
-            step[1] as i32,
-            step[0] as i32,
-        );
-
-        let lt_mask = _mm_cmplt_epi32(new_values, values_low);
-
-        values_low = _mm_or_si128(
-            _mm_and_si128(new_values, lt_mask),
-            _mm_andnot_si128(lt_mask, values_low),
-        );
-        index_low = _mm_or_si128(
-            _mm_and_si128(new_index_low, lt_mask),
-            _mm_andnot_si128(lt_mask, index_low),
-        );
-    });
+    (low, rem_offset + index)
+}
 
-    let highpack = _mm_unpackhi_epi32(values_low, values_low);
-    let lowpack = _mm_unpacklo_epi32(values_low, values_low);
-    let lowest = _mm_min_epi32(highpack, lowpack);
+#[target_feature(enable = "sse4.1")]
+unsafe fn block_min(block: &[u8]) -> u8 {
+    let mut values_low = _mm_loadu_si128(block.as_ptr() as *const __m128i);
 
-    let highpack = _mm_unpackhi_epi32(lowest, lowest);
-    let lowpack = _mm_unpacklo_epi32(lowest, lowest);
-    let lowest = _mm_min_epi32(highpack, lowpack);
+    block.chunks_exact(16).skip(1).for_each(|step| {
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        values_low = _mm_min_epu8(values_low, new_values);
+    });
 
-    let low_mask = _mm_cmpeq_epi32(lowest, values_low);
+    horizontal_min(values_low)
+}
 
-    index_low = _mm_or_si128(
-        _mm_and_si128(index_low, low_mask),
-        _mm_andnot_si128(low_mask, _mm_set1_epi32(std::i32::MAX)),
-    );
+#[target_feature(enable = "sse4.1")]
+unsafe fn horizontal_min(values_low: __m128i) -> u8 {
+    let lowest = _mm_min_epu8(values_low, _mm_srli_si128::<8>(values_low));
+    let lowest = _mm_min_epu8(lowest, _mm_srli_si128::<4>(lowest));
+    let lowest = _mm_min_epu8(lowest, _mm_srli_si128::<2>(lowest));
+    let lowest = _mm_min_epu8(lowest, _mm_srli_si128::<1>(lowest));
 
-    let value_array = std::mem::transmute::<__m128i, [u32; 4]>(values_low);
-    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_low);
+    _mm_cvtsi128_si32(lowest) as u8
+}
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+/// Position of the first `value` in `sim_arr`, or the last one when `LAST`.
+/// `sim_arr` must contain it.
+#[target_feature(enable = "sse4.1")]
+unsafe fn find_index<const LAST: bool>(sim_arr: &[u8], value: u8) -> usize {
+    let target = _mm_set1_epi8(value as i8);
+    let eq_mask = |step: &[u8]| {
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        _mm_movemask_epi8(_mm_cmpeq_epi8(new_values, target))
+    };
 
-    (value as u8, index as usize)
+    let mut steps = sim_arr.chunks_exact(16).enumerate();
+    if LAST {
+        steps.rev().find_map(|(i, step)| {
+            let eq_mask = eq_mask(step);
+            (eq_mask != 0).then(|| i * 16 + 31 - eq_mask.leading_zeros() as usize)
+        })
+    } else {
+        steps.find_map(|(i, step)| {
+            let eq_mask = eq_mask(step);
+            (eq_mask != 0).then(|| i * 16 + eq_mask.trailing_zeros() as usize)
+        })
+    }
+    .unwrap()
 }
 
-unsafe fn core_argmin_ext(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
-    let offset = _mm_set1_epi16(rem_offset as i16);
-    let mut index_low = _mm_add_epi16(_mm_set_epi16(7, 6, 5, 4, 3, 2, 1, 0), offset);
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmin_avx2<const LAST: bool>(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
+    let mut blocks = blocks::<LAST>(sim_arr);
+    let (mut low_block, block) = blocks.next().unwrap();
+    let mut low = block_min_avx2(block);
 
-    let increment = _mm_set1_epi16(8);
-    let mut new_index_low = index_low;
+    for (i, block) in blocks {
+        if low == u8::MIN {
+            break;
+        }
+        let block_low = block_min_avx2(block);
+        if block_low < low {
+            low = block_low;
+            low_block = i;
+        }
+    }
 
-    let mut values_low = _mm_set_epi16(
-        sim_arr[7] as i16,
-        sim_arr[6] as i16,
-        sim_arr[5] as i16,
-        sim_arr[4] as i16,
-        sim_arr[3] as i16,
-        sim_arr[2] as i16,
-        sim_arr[1] as i16,
-        sim_arr[0] as i16,
-    );
+    let block_offset = low_block * BLOCK_SIZE;
+    let block = &sim_arr[block_offset..sim_arr.len().min(block_offset + BLOCK_SIZE)];
+    let index = block_offset + find_index_avx2::<LAST>(block, low);
+
+    (low, rem_offset + index)
+}
 
-    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
-        new_index_low = _mm_add_epi16(new_index_low, increment);
-
-        let new_values = _mm_set_epi16(
-            step[7] as i16,
-            step[6] as i16,
-            step[5] as i16,
-            step[4] as i16,
-            step[3] as i16,
-            step[2] as i16,
-            step[1] as i16,
-            step[0] as i16,
-        );
-
-        let lt_mask = _mm_cmplt_epi16(new_values, values_low);
-
-        values_low = _mm_or_si128(
-            _mm_and_si128(new_values, lt_mask),
-            _mm_andnot_si128(lt_mask, values_low),
-        );
-        index_low = _mm_or_si128(
-            _mm_and_si128(new_index_low, lt_mask),
-            _mm_andnot_si128(lt_mask, index_low),
-        );
+#[target_feature(enable = "avx2")]
+unsafe fn block_min_avx2(block: &[u8]) -> u8 {
+    let mut values_low = _mm256_loadu_si256(block.as_ptr() as *const __m256i);
+
+    block.chunks_exact(32).skip(1).for_each(|step| {
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        values_low = _mm256_min_epu8(values_low, new_values);
     });
 
-    let highpack = _mm_unpackhi_epi16(values_low, values_low);
-    let lowpack = _mm_unpacklo_epi16(values_low, values_low);
-    let lowest = _mm_min_epi16(highpack, lowpack);
+    horizontal_min(_mm_min_epu8(
+        _mm256_castsi256_si128(values_low),
+        _mm256_extracti128_si256::<1>(values_low),
+    ))
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn find_index_avx2<const LAST: bool>(sim_arr: &[u8], value: u8) -> usize {
+    let target = _mm256_set1_epi8(value as i8);
+    let eq_mask = |step: &[u8]| {
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        _mm256_movemask_epi8(_mm256_cmpeq_epi8(new_values, target))
+    };
+
+    let mut steps = sim_arr.chunks_exact(32).enumerate();
+    if LAST {
+        steps.rev().find_map(|(i, step)| {
+            let eq_mask = eq_mask(step);
+            (eq_mask != 0).then(|| i * 32 + 31 - eq_mask.leading_zeros() as usize)
+        })
+    } else {
+        steps.find_map(|(i, step)| {
+            let eq_mask = eq_mask(step);
+            (eq_mask != 0).then(|| i * 32 + eq_mask.trailing_zeros() as usize)
+        })
+    }
+    .unwrap()
+}
 
-    let highpack = _mm_unpackhi_epi16(lowest, lowest);
-    let lowpack = _mm_unpacklo_epi16(lowest, lowest);
-    let lowest = _mm_min_epi16(highpack, lowpack);
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argmin_avx512<const LAST: bool>(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
+    let mut blocks = blocks::<LAST>(sim_arr);
+    let (mut low_block, block) = blocks.next().unwrap();
+    let mut low = block_min_avx512(block);
 
-    let highpack = _mm_unpackhi_epi16(lowest, lowest);
-    let lowpack = _mm_unpacklo_epi16(lowest, lowest);
-    let lowest = _mm_min_epi16(highpack, lowpack);
+    for (i, block) in blocks {
+        if low == u8::MIN {
+            break;
+        }
+        let block_low = block_min_avx512(block);
+        if block_low < low {
+            low = block_low;
+            low_block = i;
+        }
+    }
 
-    let low_mask = _mm_cmpeq_epi16(lowest, values_low);
+    let block_offset = low_block * BLOCK_SIZE;
+    let block = &sim_arr[block_offset..sim_arr.len().min(block_offset + BLOCK_SIZE)];
+    let index = block_offset + find_index_avx512::<LAST>(block, low);
 
-    index_low = _mm_or_si128(
-        _mm_and_si128(index_low, low_mask),
-        _mm_andnot_si128(low_mask, _mm_set1_epi16(std::i16::MAX)),
-    );
+    (low, rem_offset + index)
+}
 
-    let value_array = std::mem::transmute::<__m128i, [i16; 8]>(values_low);
-    let index_array = std::mem::transmute::<__m128i, [i16; 8]>(index_low);
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn block_min_avx512(block: &[u8]) -> u8 {
+    let mut values_low = _mm512_loadu_si512(block.as_ptr() as *const __m512i);
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+    block.chunks_exact(64).skip(1).for_each(|step| {
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        values_low = _mm512_min_epu8(values_low, new_values);
+    });
 
-    (value as u8, index as usize)
+    let values_low = _mm256_min_epu8(
+        _mm512_castsi512_si256(values_low),
+        _mm512_extracti64x4_epi64::<1>(values_low),
+    );
+    horizontal_min(_mm_min_epu8(
+        _mm256_castsi256_si128(values_low),
+        _mm256_extracti128_si256::<1>(values_low),
+    ))
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn find_index_avx512<const LAST: bool>(sim_arr: &[u8], value: u8) -> usize {
+    let target = _mm512_set1_epi8(value as i8);
+    let eq_mask = |step: &[u8]| {
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        _mm512_cmpeq_epi8_mask(new_values, target)
+    };
+
+    let mut steps = sim_arr.chunks_exact(64).enumerate();
+    if LAST {
+        steps.rev().find_map(|(i, step)| {
+            let eq_mask = eq_mask(step);
+            (eq_mask != 0).then(|| i * 64 + 63 - eq_mask.leading_zeros() as usize)
+        })
+    } else {
+        steps.find_map(|(i, step)| {
+            let eq_mask = eq_mask(step);
+            (eq_mask != 0).then(|| i * 64 + eq_mask.trailing_zeros() as usize)
+        })
+    }
+    .unwrap()
 }
 
 pub fn argmax_u8(arr: &[u8]) -> Option<usize> {
-    let n = arr.len();
-    let mut simd_func: unsafe fn(&[u8], usize) -> (u8, usize) = core_argmax;
-    let mut mod_size = 4;
+    max_with_ties::<false>(arr).map(|(_, index)| index)
+}
+
+/// Like [`argmax_u8`], but the last index is returned among equal maxima.
+pub fn argmax_u8_last(arr: &[u8]) -> Option<usize> {
+    max_with_ties::<true>(arr).map(|(_, index)| index)
+}
+
+/// The largest value of `arr` together with its first index.
+pub fn max_with_index_u8(arr: &[u8]) -> Option<(u8, usize)> {
+    max_with_ties::<false>(arr)
+}
+
+fn max_with_ties<const LAST: bool>(arr: &[u8]) -> Option<(u8, usize)> {
+    if simd_level() == SimdLevel::Scalar {
+        return (!arr.is_empty()).then(|| with_value(arr, simple_argmax_tie::<_, LAST>(arr)));
+    }
+
+    let mut simd_func: unsafe fn(&[u8], usize) -> (u8, usize) = core_argmax::<LAST>;
+    let mut mod_size = 16;
 
-    if (17..=std::i16::MAX).contains(&(n as i16)) {
-        simd_func = core_argmax_ext;
-        mod_size = 8;
+    if simd_level() == SimdLevel::Avx512 {
+        simd_func = core_argmax_avx512::<LAST>;
+        mod_size = 64;
+    } else if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argmax_avx2::<LAST>;
+        mod_size = 32;
     };
 
     match split_array(arr, mod_size) {
         (Some(rem), Some(sim)) => {
-            let rem_min_index = simple_argmax(rem);
+            let rem_min_index = simple_argmax_tie::<_, LAST>(rem);
             let rem_result = (rem[rem_min_index], rem_min_index);
             let sim_result = unsafe { simd_func(sim, rem.len()) };
-            find_final_index_max(rem_result, sim_result)
+            Some(merge_max_tie::<_, LAST>(rem_result, sim_result))
         }
-        (Some(rem), None) => Some(simple_argmax(rem)),
+        (Some(rem), None) => Some(with_value(rem, simple_argmax_tie::<_, LAST>(rem))),
         (None, Some(sim)) => {
             let sim_result = unsafe { simd_func(sim, 0) };
-            Some(sim_result.1)
+            Some(sim_result)
         }
         (None, None) => None,
     }
 }
 
-unsafe fn core_argmax(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
-    let offset = _mm_set1_epi32(rem_offset as i32);
-    let mut index_high = _mm_add_epi32(_mm_set_epi32(3, 2, 1, 0), offset);
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argmax<const LAST: bool>(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
+    let mut blocks = blocks::<LAST>(sim_arr);
+    let (mut high_block, block) = blocks.next().unwrap();
+    let mut high = block_max(block);
 
-    let mut new_index_high = index_high;
+    for (i, block) in blocks {
+        if high == u8::MAX {
+            break;
+        }
+        let block_high = block_max(block);
+        if block_high > high {
+            high = block_high;
+            high_block = i;
+        }
+    }
 
-    let increment = _mm_set1_epi32(4);
+    let block_offset = high_block * BLOCK_SIZE;
+    let block = &sim_arr[block_offset..sim_arr.len().min(block_offset + BLOCK_SIZE)];
+    let index = block_offset + find_index::<LAST>(block, high);
 
-    let mut values_high = _mm_set_epi32(
-        sim_arr[3] as i32,
-        sim_arr[2] as i32,
-        sim_arr[1] as i32,
-        sim_arr[0] as i32,
-    );
+    (high, rem_offset + index)
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn block_max(block: &[u8]) -> u8 {
+    let mut values_high = _mm_loadu_si128(block.as_ptr() as *const __m128i);
 
-    sim_arr.chunks_exact(4).skip(1).for_each(|step| {
-        new_index_high = _mm_add_epi32(new_index_high, increment);
-
-        let new_values = _mm_set_epi32(
-            step[3] as i32,
-            step[2] as i32,
-            step[1] as i32,
-            step[0] as i32,
-        );
-
-        let gt_mask = _mm_cmpgt_epi32(new_values, values_high);
-
-        values_high = _mm_or_si128(
-            _mm_and_si128(new_values, gt_mask),
-            _mm_andnot_si128(gt_mask, values_high),
-        );
-        index_high = _mm_or_si128(
-            _mm_and_si128(new_index_high, gt_mask),
-            _mm_andnot_si128(gt_mask, index_high),
-        );
+    block.chunks_exact(16).skip(1).for_each(|step| {
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        values_high = _mm_max_epu8(values_high, new_values);
     });
 
-    let highpack = _mm_unpackhi_epi32(values_high, values_high);
-    let lowpack = _mm_unpacklo_epi32(values_high, values_high);
-    let highest = _mm_max_epi32(highpack, lowpack);
+    horizontal_max(values_high)
+}
 
-    let highpack = _mm_unpackhi_epi32(highest, highest);
-    let lowpack = _mm_unpacklo_epi32(highest, highest);
-    let highest = _mm_max_epi32(highpack, lowpack);
+#[target_feature(enable = "sse4.1")]
+unsafe fn horizontal_max(values_high: __m128i) -> u8 {
+    let highest = _mm_max_epu8(values_high, _mm_srli_si128::<8>(values_high));
+    let highest = _mm_max_epu8(highest, _mm_srli_si128::<4>(highest));
+    let highest = _mm_max_epu8(highest, _mm_srli_si128::<2>(highest));
+    let highest = _mm_max_epu8(highest, _mm_srli_si128::<1>(highest));
 
-    let high_mask = _mm_cmpeq_epi32(highest, values_high);
+    _mm_cvtsi128_si32(highest) as u8
+}
 
-    index_high = _mm_or_si128(
-        _mm_and_si128(index_high, high_mask),
-        _mm_andnot_si128(high_mask, _mm_set1_epi32(std::i32::MAX)),
-    );
+#[target_feature(enable = "avx2")]
+unsafe fn core_argmax_avx2<const LAST: bool>(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
+    let mut blocks = blocks::<LAST>(sim_arr);
+    let (mut high_block, block) = blocks.next().unwrap();
+    let mut high = block_max_avx2(block);
 
-    let value_array = std::mem::transmute::<__m128i, [u32; 4]>(values_high);
-    let index_array = std::mem::transmute::<__m128i, [i32; 4]>(index_high);
+    for (i, block) in blocks {
+        if high == u8::MAX {
+            break;
+        }
+        let block_high = block_max_avx2(block);
+        if block_high > high {
+            high = block_high;
+            high_block = i;
+        }
+    }
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+    let block_offset = high_block * BLOCK_SIZE;
+    let block = &sim_arr[block_offset..sim_arr.len().min(block_offset + BLOCK_SIZE)];
+    let index = block_offset + find_index_avx2::<LAST>(block, high);
 
-    (value as u8, index as usize)
+    (high, rem_offset + index)
 }
 
-unsafe fn core_argmax_ext(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
-    let offset = _mm_set1_epi16(rem_offset as i16);
-    let mut index_high = _mm_add_epi16(_mm_set_epi16(7, 6, 5, 4, 3, 2, 1, 0), offset);
-    let mut new_index_high = index_high;
+#[target_feature(enable = "avx2")]
+unsafe fn block_max_avx2(block: &[u8]) -> u8 {
+    let mut values_high = _mm256_loadu_si256(block.as_ptr() as *const __m256i);
+
+    block.chunks_exact(32).skip(1).for_each(|step| {
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        values_high = _mm256_max_epu8(values_high, new_values);
+    });
+
+    horizontal_max(_mm_max_epu8(
+        _mm256_castsi256_si128(values_high),
+        _mm256_extracti128_si256::<1>(values_high),
+    ))
+}
 
-    let increment = _mm_set1_epi16(8);
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argmax_avx512<const LAST: bool>(sim_arr: &[u8], rem_offset: usize) -> (u8, usize) {
+    let mut blocks = blocks::<LAST>(sim_arr);
+    let (mut high_block, block) = blocks.next().unwrap();
+    let mut high = block_max_avx512(block);
 
-    let mut values_high = _mm_set_epi16(
-        sim_arr[7] as i16,
-        sim_arr[6] as i16,
-        sim_arr[5] as i16,
-        sim_arr[4] as i16,
-        sim_arr[3] as i16,
-        sim_arr[2] as i16,
-        sim_arr[1] as i16,
-        sim_arr[0] as i16,
+    for (i, block) in blocks {
+        if high == u8::MAX {
+            break;
+        }
+        let block_high = block_max_avx512(block);
+        if block_high > high {
+            high = block_high;
+            high_block = i;
+        }
+    }
+
+    let block_offset = high_block * BLOCK_SIZE;
+    let block = &sim_arr[block_offset..sim_arr.len().min(block_offset + BLOCK_SIZE)];
+    let index = block_offset + find_index_avx512::<LAST>(block, high);
+
+    (high, rem_offset + index)
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn block_max_avx512(block: &[u8]) -> u8 {
+    let mut values_high = _mm512_loadu_si512(block.as_ptr() as *const __m512i);
+
+    block.chunks_exact(64).skip(1).for_each(|step| {
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        values_high = _mm512_max_epu8(values_high, new_values);
+    });
+
+    let values_high = _mm256_max_epu8(
+        _mm512_castsi512_si256(values_high),
+        _mm512_extracti64x4_epi64::<1>(values_high),
     );
+    horizontal_max(_mm_max_epu8(
+        _mm256_castsi256_si128(values_high),
+        _mm256_extracti128_si256::<1>(values_high),
+    ))
+}
+
+pub fn argminmax_u8(arr: &[u8]) -> Option<(usize, usize)> {
+    minmax_with_indices_u8(arr).map(|((_, min), (_, max))| (min, max))
+}
+
+/// The smallest and largest values of `arr` together with their first indices.
+pub fn minmax_with_indices_u8(arr: &[u8]) -> Option<MinMaxResult<u8>> {
+    if simd_level() == SimdLevel::Scalar {
+        return (!arr.is_empty()).then(|| with_values(arr, simple_argminmax(arr)));
+    }
+
+    let mut simd_func: unsafe fn(&[u8], usize) -> MinMaxResult<u8> = core_argminmax;
+    let mut mod_size = 16;
 
-    sim_arr.chunks_exact(8).skip(1).for_each(|step| {
-        new_index_high = _mm_add_epi16(new_index_high, increment);
-
-        let new_values = _mm_set_epi16(
-            step[7] as i16,
-            step[6] as i16,
-            step[5] as i16,
-            step[4] as i16,
-            step[3] as i16,
-            step[2] as i16,
-            step[1] as i16,
-            step[0] as i16,
-        );
-
-        let gt_mask = _mm_cmpgt_epi16(new_values, values_high);
-
-        values_high = _mm_or_si128(
-            _mm_and_si128(new_values, gt_mask),
-            _mm_andnot_si128(gt_mask, values_high),
-        );
-        index_high = _mm_or_si128(
-            _mm_and_si128(new_index_high, gt_mask),
-            _mm_andnot_si128(gt_mask, index_high),
-        );
+    if simd_level() == SimdLevel::Avx512 {
+        simd_func = core_argminmax_avx512;
+        mod_size = 64;
+    } else if simd_level() >= SimdLevel::Avx2 {
+        simd_func = core_argminmax_avx2;
+        mod_size = 32;
+    };
+
+    match split_array(arr, mod_size) {
+        (Some(rem), Some(sim)) => {
+            let (rem_min_index, rem_max_index) = simple_argminmax(rem);
+            let rem_min_result = (rem[rem_min_index], rem_min_index);
+            let rem_max_result = (rem[rem_max_index], rem_max_index);
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, rem.len()) };
+            Some((
+                merge_min(rem_min_result, sim_min_result),
+                merge_max(rem_max_result, sim_max_result),
+            ))
+        }
+        (Some(rem), None) => Some(with_values(rem, simple_argminmax(rem))),
+        (None, Some(sim)) => {
+            let (sim_min_result, sim_max_result) = unsafe { simd_func(sim, 0) };
+            Some((sim_min_result, sim_max_result))
+        }
+        (None, None) => None,
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn core_argminmax(sim_arr: &[u8], rem_offset: usize) -> MinMaxResult<u8> {
+    let (mut low, mut high) = (u8::MAX, u8::MIN);
+    let (mut low_block, mut high_block) = (0, 0);
+
+    for (i, block) in sim_arr.chunks(BLOCK_SIZE).enumerate() {
+        let (block_low, block_high) = block_minmax(block);
+        if block_low < low {
+            low = block_low;
+            low_block = i;
+        }
+        if block_high > high {
+            high = block_high;
+            high_block = i;
+        }
+        if low == u8::MIN && high == u8::MAX {
+            break;
+        }
+    }
+
+    let block_offset = low_block * BLOCK_SIZE;
+    let low_index = block_offset + find_index::<false>(&sim_arr[block_offset..], low);
+    let block_offset = high_block * BLOCK_SIZE;
+    let high_index = block_offset + find_index::<false>(&sim_arr[block_offset..], high);
+
+    (
+        (low, rem_offset + low_index),
+        (high, rem_offset + high_index),
+    )
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn block_minmax(block: &[u8]) -> (u8, u8) {
+    let mut values_low = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+    let mut values_high = values_low;
+
+    block.chunks_exact(16).skip(1).for_each(|step| {
+        let new_values = _mm_loadu_si128(step.as_ptr() as *const __m128i);
+        values_low = _mm_min_epu8(values_low, new_values);
+        values_high = _mm_max_epu8(values_high, new_values);
     });
 
-    let highpack = _mm_unpackhi_epi16(values_high, values_high);
-    let lowpack = _mm_unpacklo_epi16(values_high, values_high);
-    let highest = _mm_max_epi16(highpack, lowpack);
+    (horizontal_min(values_low), horizontal_max(values_high))
+}
 
-    let highpack = _mm_unpackhi_epi16(highest, highest);
-    let lowpack = _mm_unpacklo_epi16(highest, highest);
-    let highest = _mm_max_epi16(highpack, lowpack);
+#[target_feature(enable = "avx2")]
+unsafe fn core_argminmax_avx2(sim_arr: &[u8], rem_offset: usize) -> MinMaxResult<u8> {
+    let (mut low, mut high) = (u8::MAX, u8::MIN);
+    let (mut low_block, mut high_block) = (0, 0);
 
-    let highpack = _mm_unpackhi_epi16(highest, highest);
-    let lowpack = _mm_unpacklo_epi16(highest, highest);
-    let highest = _mm_max_epi16(highpack, lowpack);
+    for (i, block) in sim_arr.chunks(BLOCK_SIZE).enumerate() {
+        let (block_low, block_high) = block_minmax_avx2(block);
+        if block_low < low {
+            low = block_low;
+            low_block = i;
+        }
+        if block_high > high {
+            high = block_high;
+            high_block = i;
+        }
+        if low == u8::MIN && high == u8::MAX {
+            break;
+        }
+    }
+
+    let block_offset = low_block * BLOCK_SIZE;
+    let low_index = block_offset + find_index_avx2::<false>(&sim_arr[block_offset..], low);
+    let block_offset = high_block * BLOCK_SIZE;
+    let high_index = block_offset + find_index_avx2::<false>(&sim_arr[block_offset..], high);
 
-    let high_mask = _mm_cmpeq_epi16(highest, values_high);
+    (
+        (low, rem_offset + low_index),
+        (high, rem_offset + high_index),
+    )
+}
 
-    index_high = _mm_or_si128(
-        _mm_and_si128(index_high, high_mask),
-        _mm_andnot_si128(high_mask, _mm_set1_epi16(std::i16::MAX)),
+#[target_feature(enable = "avx2")]
+unsafe fn block_minmax_avx2(block: &[u8]) -> (u8, u8) {
+    let mut values_low = _mm256_loadu_si256(block.as_ptr() as *const __m256i);
+    let mut values_high = values_low;
+
+    block.chunks_exact(32).skip(1).for_each(|step| {
+        let new_values = _mm256_loadu_si256(step.as_ptr() as *const __m256i);
+        values_low = _mm256_min_epu8(values_low, new_values);
+        values_high = _mm256_max_epu8(values_high, new_values);
+    });
+
+    let values_low = _mm_min_epu8(
+        _mm256_castsi256_si128(values_low),
+        _mm256_extracti128_si256::<1>(values_low),
     );
+    let values_high = _mm_max_epu8(
+        _mm256_castsi256_si128(values_high),
+        _mm256_extracti128_si256::<1>(values_high),
+    );
+
+    (horizontal_min(values_low), horizontal_max(values_high))
+}
+
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn core_argminmax_avx512(sim_arr: &[u8], rem_offset: usize) -> MinMaxResult<u8> {
+    let (mut low, mut high) = (u8::MAX, u8::MIN);
+    let (mut low_block, mut high_block) = (0, 0);
+
+    for (i, block) in sim_arr.chunks(BLOCK_SIZE).enumerate() {
+        let (block_low, block_high) = block_minmax_avx512(block);
+        if block_low < low {
+            low = block_low;
+            low_block = i;
+        }
+        if block_high > high {
+            high = block_high;
+            high_block = i;
+        }
+        if low == u8::MIN && high == u8::MAX {
+            break;
+        }
+    }
+
+    let block_offset = low_block * BLOCK_SIZE;
+    let low_index = block_offset + find_index_avx512::<false>(&sim_arr[block_offset..], low);
+    let block_offset = high_block * BLOCK_SIZE;
+    let high_index = block_offset + find_index_avx512::<false>(&sim_arr[block_offset..], high);
+
+    (
+        (low, rem_offset + low_index),
+        (high, rem_offset + high_index),
+    )
+}
 
-    let value_array = std::mem::transmute::<__m128i, [i16; 8]>(values_high);
-    let index_array = std::mem::transmute::<__m128i, [i16; 8]>(index_high);
+#[target_feature(enable = "avx512f,avx512bw")]
+unsafe fn block_minmax_avx512(block: &[u8]) -> (u8, u8) {
+    let mut values_low = _mm512_loadu_si512(block.as_ptr() as *const __m512i);
+    let mut values_high = values_low;
 
-    let min_index = simple_argmin(&index_array);
-    let value = *value_array.get_unchecked(min_index);
-    let index = *index_array.get_unchecked(min_index);
+    block.chunks_exact(64).skip(1).for_each(|step| {
+        let new_values = _mm512_loadu_si512(step.as_ptr() as *const __m512i);
+        values_low = _mm512_min_epu8(values_low, new_values);
+        values_high = _mm512_max_epu8(values_high, new_values);
+    });
+
+    let values_low = _mm256_min_epu8(
+        _mm512_castsi512_si256(values_low),
+        _mm512_extracti64x4_epi64::<1>(values_low),
+    );
+    let values_high = _mm256_max_epu8(
+        _mm512_castsi512_si256(values_high),
+        _mm512_extracti64x4_epi64::<1>(values_high),
+    );
+    let values_low = _mm_min_epu8(
+        _mm256_castsi256_si128(values_low),
+        _mm256_extracti128_si256::<1>(values_low),
+    );
+    let values_high = _mm_max_epu8(
+        _mm256_castsi256_si128(values_high),
+        _mm256_extracti128_si256::<1>(values_high),
+    );
 
-    (value as u8, index as usize)
+    (horizontal_min(values_low), horizontal_max(values_high))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{argmax_u8, argmin_u8, simple_argmax, simple_argmin};
+    use super::{
+        argmax_u8, argmax_u8_last, argmin_u8, argmin_u8_last, argminmax_u8, core_argmax,
+        core_argmax_avx2, core_argmax_avx512, core_argmin, core_argmin_avx2, core_argmin_avx512,
+        core_argminmax, core_argminmax_avx2, core_argminmax_avx512,
+    };
+    use crate::generic::{simple_argmax, simple_argmin};
+    use crate::generic::{simple_argmax_last, simple_argmin_last};
     use rand::{thread_rng, Rng};
     use rand_distr::Uniform;
 
     fn get_array_u8(n: usize) -> Vec<u8> {
         let rng = thread_rng();
-        let uni = Uniform::new_inclusive(std::u8::MIN, std::u8::MAX);
+        let uni = Uniform::new_inclusive(u8::MIN, u8::MAX);
         rng.sample_iter(uni).take(n).collect()
     }
 
@@ -349,6 +676,146 @@ mod tests {
         assert_eq!(argmax_index, max_index);
     }
 
+    #[test]
+    fn test_sse_and_avx2_kernels_return_the_same_results() {
+        let data = get_array_u8(1024);
+        let argmin_index = simple_argmin(&data);
+        let argmax_index = simple_argmax(&data);
+
+        if is_x86_feature_detected!("sse4.1") {
+            assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+            assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, argmax_index);
+            let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(
+                unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                argmin_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx2::<false>(&data, 0) }.1,
+                argmax_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (argmin_index, argmax_index));
+        }
+    }
+
+    #[test]
+    fn test_kernels_handle_arrays_past_i16_range() {
+        for n in [32_768, 40_000, 65_568, 100_000] {
+            let mut data: Vec<u8> = get_array_u8(n)
+                .into_iter()
+                .map(|x| x.clamp(1, u8::MAX - 1))
+                .collect();
+            data[32_761] = u8::MIN;
+            data[n - 11] = u8::MIN;
+            data[n - 5] = u8::MAX;
+
+            let argmin_index = (n - 11).min(32_761);
+
+            if is_x86_feature_detected!("sse4.1") {
+                assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, argmin_index);
+                assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, n - 5);
+                let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 3) };
+                assert_eq!((low, high), (argmin_index + 3, n - 2));
+            }
+
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(
+                    unsafe { core_argmin_avx2::<false>(&data, 0) }.1,
+                    argmin_index
+                );
+                assert_eq!(unsafe { core_argmax_avx2::<false>(&data, 0) }.1, n - 5);
+                let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 3) };
+                assert_eq!((low, high), (argmin_index + 3, n - 2));
+            }
+
+            let data = get_array_u8(n + 3);
+            assert_eq!(argmin_u8(&data), Some(simple_argmin(&data)));
+            assert_eq!(argmax_u8(&data), Some(simple_argmax(&data)));
+        }
+    }
+
+    #[test]
+    fn test_kernels_return_first_index_across_blocks() {
+        let mut data = vec![100u8; 4096];
+        data[2500] = 3;
+        data[3900] = 3;
+        data[1100] = 200;
+        data[1101] = 200;
+        data[3000] = 200;
+
+        if is_x86_feature_detected!("sse4.1") {
+            assert_eq!(unsafe { core_argmin::<false>(&data, 0) }.1, 2500);
+            assert_eq!(unsafe { core_argmax::<false>(&data, 0) }.1, 1100);
+            let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+            assert_eq!((low, high), (2500, 1100));
+        }
+
+        if is_x86_feature_detected!("avx2") {
+            assert_eq!(unsafe { core_argmin_avx2::<false>(&data, 0) }.1, 2500);
+            assert_eq!(unsafe { core_argmax_avx2::<false>(&data, 0) }.1, 1100);
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (2500, 1100));
+        }
+
+        let data = vec![7u8; 4096];
+        if is_x86_feature_detected!("sse4.1") {
+            let ((_, low), (_, high)) = unsafe { core_argminmax(&data, 0) };
+            assert_eq!((low, high), (0, 0));
+        }
+        if is_x86_feature_detected!("avx2") {
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx2(&data, 0) };
+            assert_eq!((low, high), (0, 0));
+        }
+    }
+
+    #[test]
+    fn test_avx512_kernels_return_the_same_results() {
+        if !(is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512bw")) {
+            return;
+        }
+
+        for n in [64, 1024, 4160, 70_016] {
+            let mut data: Vec<u8> = get_array_u8(n)
+                .into_iter()
+                .map(|x| x.clamp(1, u8::MAX - 1))
+                .collect();
+            data[n - 40] = u8::MIN;
+            data[n - 7] = u8::MIN;
+            data[5] = u8::MAX;
+            data[n - 20] = u8::MAX;
+
+            assert_eq!(unsafe { core_argmin_avx512::<false>(&data, 0) }.1, n - 40);
+            assert_eq!(unsafe { core_argmax_avx512::<false>(&data, 0) }.1, 5);
+            assert_eq!(unsafe { core_argmin_avx512::<true>(&data, 0) }.1, n - 7);
+            assert_eq!(unsafe { core_argmax_avx512::<true>(&data, 0) }.1, n - 20);
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data, 3) };
+            assert_eq!((low, high), (n - 37, 8));
+
+            let data = get_array_u8(n);
+            let (min_index, max_index) = (simple_argmin(&data), simple_argmax(&data));
+            assert_eq!(
+                unsafe { core_argmin_avx512::<false>(&data, 0) }.1,
+                min_index
+            );
+            assert_eq!(
+                unsafe { core_argmax_avx512::<false>(&data, 0) }.1,
+                max_index
+            );
+            let ((_, low), (_, high)) = unsafe { core_argminmax_avx512(&data, 0) };
+            assert_eq!((low, high), (min_index, max_index));
+
+            let data: Vec<u8> = data.iter().map(|x| x % 7).collect();
+            let (min_index, max_index) = (simple_argmin_last(&data), simple_argmax_last(&data));
+            assert_eq!(unsafe { core_argmin_avx512::<true>(&data, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax_avx512::<true>(&data, 0) }.1, max_index);
+        }
+    }
+
     #[test]
     fn test_both_versions_return_the_same_results_long_array() {
         let data = get_array_u8(1025);
@@ -365,7 +832,7 @@ mod tests {
 
     #[test]
     fn test_first_index_is_returned_when_identical_values_found() {
-        let data = [10, std::u8::MIN, 6, 9, 9, 22, std::u8::MAX, 4, std::u8::MAX];
+        let data = [10, u8::MIN, 6, 9, 9, 22, u8::MAX, 4, u8::MAX];
         let argmin_index = simple_argmin(&data);
         let argmin_simd_index = argmin_u8(&data).unwrap();
         assert_eq!(argmin_index, argmin_simd_index);
@@ -376,4 +843,32 @@ mod tests {
         assert_eq!(argmax_index, argmax_simd_index);
         assert_eq!(argmax_index, 6);
     }
+
+    #[test]
+    fn test_last_index_is_returned_when_identical_values_found() {
+        for n in [32, 33, 1025, 4099, 70_001] {
+            let data: Vec<u8> = get_array_u8(n).iter().map(|x| x % 7).collect();
+            assert_eq!(argmin_u8_last(&data), Some(simple_argmin_last(&data)));
+            assert_eq!(argmax_u8_last(&data), Some(simple_argmax_last(&data)));
+
+            let sim = &data[..n - n % 32];
+            let (min_index, max_index) = (simple_argmin_last(sim), simple_argmax_last(sim));
+            assert_eq!(unsafe { core_argmin::<true>(sim, 0) }.1, min_index);
+            assert_eq!(unsafe { core_argmax::<true>(sim, 0) }.1, max_index);
+            if is_x86_feature_detected!("avx2") {
+                assert_eq!(unsafe { core_argmin_avx2::<true>(sim, 0) }.1, min_index);
+                assert_eq!(unsafe { core_argmax_avx2::<true>(sim, 0) }.1, max_index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_argminmax_matches_separate_calls() {
+        for n in [7, 8, 9, 1025, 40_001] {
+            let data = get_array_u8(n);
+            let (min_index, max_index) = argminmax_u8(&data).unwrap();
+            assert_eq!(min_index, simple_argmin(&data));
+            assert_eq!(max_index, simple_argmax(&data));
+        }
+    }
 }
@@ -1,9 +1,28 @@
+use crate::generic::KBest;
+#[cfg(target_arch = "x86_64")]
+use crate::generic::{simple_argmax, simple_argmax_last, simple_argmin, simple_argmin_last};
+#[cfg(target_arch = "x86_64")]
+use crate::Mask;
 use std::cmp::Ordering;
 
+pub(crate) type MinMaxResult<T> = ((T, usize), (T, usize));
+
+/// Extremum of the selected elements, `None` when the mask selects nothing.
+#[cfg(target_arch = "x86_64")]
+pub(crate) type MaskedResult<T> = Option<(T, usize)>;
+
+/// Keeps one of two `(value, index)` candidates, such as [`merge_min`].
+#[cfg(target_arch = "x86_64")]
+pub(crate) type Merge<T> = fn((T, usize), (T, usize)) -> (T, usize);
+
 #[inline]
 pub(crate) fn split_array<T: Copy>(arr: &[T], lane_size: usize) -> (Option<&[T]>, Option<&[T]>) {
     let n = arr.len();
 
+    if n == 0 {
+        return (None, None);
+    };
+
     if n < lane_size * 2 {
         return (Some(arr), None);
     };
@@ -23,23 +42,454 @@ pub fn find_final_index_min<T: PartialOrd>(
     remainder_result: (T, usize),
     simd_result: (T, usize),
 ) -> Option<usize> {
-    let result = match remainder_result.0.partial_cmp(&simd_result.0).unwrap() {
-        Ordering::Less => remainder_result.1,
-        Ordering::Equal => std::cmp::min(remainder_result.1, simd_result.1),
-        Ordering::Greater => simd_result.1,
+    find_final_index_min_tie::<T, false>(remainder_result, simd_result)
+}
+
+#[inline]
+pub fn find_final_index_max<T: PartialOrd>(
+    remainder_result: (T, usize),
+    simd_result: (T, usize),
+) -> Option<usize> {
+    find_final_index_max_tie::<T, false>(remainder_result, simd_result)
+}
+
+/// Pairs a scalar result with its value, as the kernels return it.
+#[inline]
+pub(crate) fn with_value<T: Copy>(arr: &[T], index: usize) -> (T, usize) {
+    (arr[index], index)
+}
+
+/// [`with_value`] for both indices of an argminmax.
+#[inline]
+pub(crate) fn with_values<T: Copy>(arr: &[T], (min, max): (usize, usize)) -> MinMaxResult<T> {
+    (with_value(arr, min), with_value(arr, max))
+}
+
+/// [`find_final_index_min`] returning the `(value, index)` pair that was kept.
+#[inline]
+pub(crate) fn merge_min<T: Copy + PartialOrd>(a: (T, usize), b: (T, usize)) -> (T, usize) {
+    merge_min_tie::<T, false>(a, b)
+}
+
+/// [`find_final_index_max`] returning the `(value, index)` pair that was kept.
+#[inline]
+pub(crate) fn merge_max<T: Copy + PartialOrd>(a: (T, usize), b: (T, usize)) -> (T, usize) {
+    merge_max_tie::<T, false>(a, b)
+}
+
+/// [`merge_min`] that keeps the larger index on ties when `LAST`.
+#[inline]
+pub(crate) fn merge_min_tie<T: Copy + PartialOrd, const LAST: bool>(
+    a: (T, usize),
+    b: (T, usize),
+) -> (T, usize) {
+    if find_final_index_min_tie::<T, LAST>(a, b) == Some(a.1) {
+        a
+    } else {
+        b
+    }
+}
+
+/// [`merge_max`] that keeps the larger index on ties when `LAST`.
+#[inline]
+pub(crate) fn merge_max_tie<T: Copy + PartialOrd, const LAST: bool>(
+    a: (T, usize),
+    b: (T, usize),
+) -> (T, usize) {
+    if find_final_index_max_tie::<T, LAST>(a, b) == Some(a.1) {
+        a
+    } else {
+        b
+    }
+}
+
+/// [`find_final_index_min`] that keeps the larger index on ties when `LAST`.
+#[inline]
+pub(crate) fn find_final_index_min_tie<T: PartialOrd, const LAST: bool>(
+    remainder_result: (T, usize),
+    simd_result: (T, usize),
+) -> Option<usize> {
+    let result = match remainder_result.0.partial_cmp(&simd_result.0) {
+        Some(Ordering::Less) => remainder_result.1,
+        Some(Ordering::Equal) => tie_index::<LAST>(remainder_result.1, simd_result.1),
+        Some(Ordering::Greater) => simd_result.1,
+        None => find_final_index_unordered::<T, LAST>(remainder_result, simd_result),
     };
     Some(result)
 }
 
+/// [`find_final_index_max`] that keeps the larger index on ties when `LAST`.
 #[inline]
-pub fn find_final_index_max<T: PartialOrd>(
+pub(crate) fn find_final_index_max_tie<T: PartialOrd, const LAST: bool>(
     remainder_result: (T, usize),
     simd_result: (T, usize),
 ) -> Option<usize> {
-    let result = match simd_result.0.partial_cmp(&remainder_result.0).unwrap() {
-        Ordering::Less => remainder_result.1,
-        Ordering::Equal => std::cmp::min(remainder_result.1, simd_result.1),
-        Ordering::Greater => simd_result.1,
+    let result = match simd_result.0.partial_cmp(&remainder_result.0) {
+        Some(Ordering::Less) => remainder_result.1,
+        Some(Ordering::Equal) => tie_index::<LAST>(remainder_result.1, simd_result.1),
+        Some(Ordering::Greater) => simd_result.1,
+        None => find_final_index_unordered::<T, LAST>(remainder_result, simd_result),
     };
     Some(result)
 }
+
+#[inline]
+fn tie_index<const LAST: bool>(a: usize, b: usize) -> usize {
+    if LAST {
+        std::cmp::max(a, b)
+    } else {
+        std::cmp::min(a, b)
+    }
+}
+
+/// Picks between two candidates that cannot be ordered because at least one
+/// of them is NaN. A comparable value is preferred over a NaN, otherwise the
+/// first index wins, or the last one when `LAST`.
+#[inline]
+fn find_final_index_unordered<T: PartialOrd, const LAST: bool>(
+    remainder_result: (T, usize),
+    simd_result: (T, usize),
+) -> usize {
+    let remainder_is_nan = remainder_result
+        .0
+        .partial_cmp(&remainder_result.0)
+        .is_none();
+    let simd_is_nan = simd_result.0.partial_cmp(&simd_result.0).is_none();
+    match (remainder_is_nan, simd_is_nan) {
+        (false, true) => remainder_result.1,
+        (true, false) => simd_result.1,
+        _ => tie_index::<LAST>(remainder_result.1, simd_result.1),
+    }
+}
+
+/// Scalar argmin of a kernel's remainder, keeping the last index on ties when
+/// `LAST`.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) fn simple_argmin_tie<T: Copy + PartialOrd, const LAST: bool>(arr: &[T]) -> usize {
+    if LAST {
+        simple_argmin_last(arr)
+    } else {
+        simple_argmin(arr)
+    }
+}
+
+/// Scalar argmax of a kernel's remainder, see [`simple_argmin_tie`].
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) fn simple_argmax_tie<T: Copy + PartialOrd, const LAST: bool>(arr: &[T]) -> usize {
+    if LAST {
+        simple_argmax_last(arr)
+    } else {
+        simple_argmax(arr)
+    }
+}
+
+/// Block size of the backward search in [`last_occurrence`].
+#[cfg(all(
+    not(target_arch = "x86_64"),
+    any(target_arch = "aarch64", feature = "portable")
+))]
+const LAST_OCCURRENCE_BLOCK_SIZE: usize = 256;
+
+/// Turns the first index of an extremum into its last one by searching
+/// backwards for the same value, for kernels without a last-index mode. Whole
+/// blocks are compared without early exit so the search vectorizes. A NaN
+/// extremum never compares equal and keeps `first`.
+#[cfg(all(
+    not(target_arch = "x86_64"),
+    any(target_arch = "aarch64", feature = "portable")
+))]
+pub(crate) fn last_occurrence<T: Copy + PartialEq>(arr: &[T], first: usize) -> usize {
+    let value = arr[first];
+    let tail = &arr[first..];
+    let mut end = tail.len();
+    for block in tail.rchunks(LAST_OCCURRENCE_BLOCK_SIZE) {
+        let start = end - block.len();
+        if block
+            .iter()
+            .fold(false, |found, item| found | (*item == value))
+        {
+            return match block.iter().rposition(|item| *item == value) {
+                Some(position) => first + start + position,
+                None => first,
+            };
+        }
+        end = start;
+    }
+    first
+}
+
+/// Largest block an 8-lane kernel counting its steps in `u16` lanes can scan
+/// before the counters wrap.
+#[cfg(target_arch = "x86_64")]
+pub(crate) const STEP_BLOCK_SIZE_SSE: usize = 8 << 16;
+
+/// Same as [`STEP_BLOCK_SIZE_SSE`] for the 16-lane AVX2 kernels.
+#[cfg(target_arch = "x86_64")]
+pub(crate) const STEP_BLOCK_SIZE_AVX2: usize = 16 << 16;
+
+/// Same as [`STEP_BLOCK_SIZE_SSE`] for the 32-lane AVX-512 kernels.
+#[cfg(target_arch = "x86_64")]
+pub(crate) const STEP_BLOCK_SIZE_AVX512: usize = 32 << 16;
+
+/// Largest block a kernel with `i32` index lanes can scan before its indices
+/// wrap, a multiple of 16 lanes.
+#[cfg(any(target_arch = "x86_64", feature = "portable"))]
+pub(crate) const I32_BLOCK_SIZE: usize = 1 << 30;
+
+/// Runs `core_func` on consecutive blocks of at most `block_size` elements and
+/// merges the per-block winners, rebasing each block's index with a `usize`
+/// offset. Ties between blocks go to the first one, or the last when `LAST`.
+/// `block_size` must be a multiple of the kernel's lane count.
+#[cfg(any(target_arch = "x86_64", feature = "portable"))]
+#[inline]
+pub(crate) unsafe fn blocked_argmin<T: Copy + PartialOrd, const LAST: bool>(
+    sim_arr: &[T],
+    rem_offset: usize,
+    block_size: usize,
+    core_func: unsafe fn(&[T], usize) -> (T, usize),
+) -> (T, usize) {
+    sim_arr
+        .chunks(block_size)
+        .enumerate()
+        .map(|(i, block)| {
+            let (value, index) = core_func(block, 0);
+            (value, rem_offset + i * block_size + index)
+        })
+        .reduce(merge_min_tie::<T, LAST>)
+        .unwrap()
+}
+
+/// Same as [`blocked_argmin`] for the largest value.
+#[cfg(any(target_arch = "x86_64", feature = "portable"))]
+#[inline]
+pub(crate) unsafe fn blocked_argmax<T: Copy + PartialOrd, const LAST: bool>(
+    sim_arr: &[T],
+    rem_offset: usize,
+    block_size: usize,
+    core_func: unsafe fn(&[T], usize) -> (T, usize),
+) -> (T, usize) {
+    sim_arr
+        .chunks(block_size)
+        .enumerate()
+        .map(|(i, block)| {
+            let (value, index) = core_func(block, 0);
+            (value, rem_offset + i * block_size + index)
+        })
+        .reduce(merge_max_tie::<T, LAST>)
+        .unwrap()
+}
+
+/// Same as [`blocked_argmin`] for kernels returning both extremes.
+#[cfg(any(target_arch = "x86_64", feature = "portable"))]
+#[inline]
+pub(crate) unsafe fn blocked_argminmax<T: Copy + PartialOrd>(
+    sim_arr: &[T],
+    rem_offset: usize,
+    block_size: usize,
+    core_func: unsafe fn(&[T], usize) -> MinMaxResult<T>,
+) -> MinMaxResult<T> {
+    sim_arr
+        .chunks(block_size)
+        .enumerate()
+        .map(|(i, block)| {
+            let base = rem_offset + i * block_size;
+            let ((low, low_index), (high, high_index)) = core_func(block, 0);
+            ((low, base + low_index), (high, base + high_index))
+        })
+        .reduce(|(low, high), (block_low, block_high)| {
+            (merge_min(low, block_low), merge_max(high, block_high))
+        })
+        .unwrap()
+}
+
+/// Same as [`blocked_argmin`] for the masked kernels, which are also handed
+/// the position of their block in `mask`. Blocks the mask leaves out find
+/// nothing, the others are reduced with `merge`.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) unsafe fn blocked_masked<T: Copy + PartialOrd>(
+    sim_arr: &[T],
+    mask: Mask,
+    block_size: usize,
+    core_func: unsafe fn(&[T], Mask, usize) -> MaskedResult<T>,
+    merge: Merge<T>,
+) -> MaskedResult<T> {
+    sim_arr
+        .chunks(block_size)
+        .enumerate()
+        .filter_map(|(i, block)| {
+            let base = i * block_size;
+            core_func(block, mask, base).map(|(value, index)| (value, base + index))
+        })
+        .reduce(merge)
+}
+
+/// Runs an 8-lane gather kernel on consecutive runs of at most
+/// `block_size / 8` gathers and reduces the winners with `merge`, rebasing
+/// each index, counted in strided elements, with a `usize` offset.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) unsafe fn blocked_gathers<T: Copy + PartialOrd>(
+    arr: &[T],
+    stride: usize,
+    gathers: usize,
+    block_size: usize,
+    core_func: unsafe fn(&[T], usize, usize) -> (T, usize),
+    merge: Merge<T>,
+) -> (T, usize) {
+    let block_gathers = block_size / 8;
+    (0..gathers)
+        .step_by(block_gathers)
+        .map(|first| {
+            let count = block_gathers.min(gathers - first);
+            let (value, index) = core_func(&arr[first * 8 * stride..], stride, count);
+            (value, first * 8 + index)
+        })
+        .reduce(merge)
+        .unwrap()
+}
+
+/// Number of elements [`prefiltered_top_k`] hands to the SIMD kernel at a time.
+const TOP_K_BLOCK_SIZE: usize = 1024;
+
+/// Indices of the `k` smallest (or `largest`) values, ordered by value and
+/// then by index. Once `k` candidates are kept, each block is first reduced
+/// with `kernel` and only scanned element by element when its extreme can
+/// still get in. Elements equal to the worst kept value come later than it,
+/// so they lose the tie and the block can be skipped.
+pub(crate) fn prefiltered_top_k<T: Copy + PartialOrd>(
+    arr: &[T],
+    k: usize,
+    largest: bool,
+    kernel: fn(&[T]) -> Option<usize>,
+) -> Vec<usize> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut best = KBest::new(k, k.min(arr.len()), largest);
+
+    for (i, block) in arr.chunks(TOP_K_BLOCK_SIZE).enumerate() {
+        if let (Some(threshold), Some(extreme_index)) = (best.threshold(), kernel(block)) {
+            let extreme = block[extreme_index];
+            // Written so that a NaN on either side never skips the block: a
+            // NaN threshold is beaten by any number, and a NaN extreme says
+            // nothing about the numbers next to it.
+            let cannot_get_in = if largest {
+                extreme <= threshold
+            } else {
+                extreme >= threshold
+            };
+            if cannot_get_in {
+                continue;
+            }
+        }
+
+        let offset = i * TOP_K_BLOCK_SIZE;
+        for (j, item) in block.iter().enumerate() {
+            best.push(*item, offset + j);
+        }
+    }
+
+    best.into_indices()
+}
+
+/// Number of strided elements copied out per call of the contiguous kernel.
+const STRIDED_BLOCK_SIZE: usize = 1024;
+
+/// Argmin of `arr[0]`, `arr[stride]`, `arr[2 * stride]`, ... counted in
+/// strided elements. The elements are copied out a block at a time so the
+/// contiguous `kernel` can run on them.
+pub(crate) fn deinterleaved_argmin<T: Copy + PartialOrd>(
+    arr: &[T],
+    stride: usize,
+    kernel: fn(&[T]) -> Option<usize>,
+) -> Option<usize> {
+    assert!(stride > 0, "stride must be non-zero");
+
+    if stride == 1 {
+        return kernel(arr);
+    }
+
+    let mut buffer = Vec::with_capacity(STRIDED_BLOCK_SIZE.min(arr.len()));
+    let mut low: Option<(T, usize)> = None;
+
+    for (i, block) in arr.chunks(STRIDED_BLOCK_SIZE * stride).enumerate() {
+        deinterleave(block, stride, &mut buffer);
+        let index = kernel(&buffer)?;
+        let block_low = (buffer[index], i * STRIDED_BLOCK_SIZE + index);
+        match low {
+            Some((value, _)) if block_low.0 >= value => {}
+            _ => low = Some(block_low),
+        }
+    }
+
+    low.map(|(_, index)| index)
+}
+
+/// Same as [`deinterleaved_argmin`], keeping the first index of the largest value.
+pub(crate) fn deinterleaved_argmax<T: Copy + PartialOrd>(
+    arr: &[T],
+    stride: usize,
+    kernel: fn(&[T]) -> Option<usize>,
+) -> Option<usize> {
+    assert!(stride > 0, "stride must be non-zero");
+
+    if stride == 1 {
+        return kernel(arr);
+    }
+
+    let mut buffer = Vec::with_capacity(STRIDED_BLOCK_SIZE.min(arr.len()));
+    let mut high: Option<(T, usize)> = None;
+
+    for (i, block) in arr.chunks(STRIDED_BLOCK_SIZE * stride).enumerate() {
+        deinterleave(block, stride, &mut buffer);
+        let index = kernel(&buffer)?;
+        let block_high = (buffer[index], i * STRIDED_BLOCK_SIZE + index);
+        match high {
+            Some((value, _)) if block_high.0 <= value => {}
+            _ => high = Some(block_high),
+        }
+    }
+
+    high.map(|(_, index)| index)
+}
+
+/// Replaces the contents of `buffer` with every `stride`-th element of `block`.
+/// Strides of 2, 3 and 4 get a copy loop with a constant step the compiler
+/// can turn into shuffles.
+#[inline]
+fn deinterleave<T: Copy>(block: &[T], stride: usize, buffer: &mut Vec<T>) {
+    buffer.clear();
+    match stride {
+        2 => deinterleave_exact::<T, 2>(block, buffer),
+        3 => deinterleave_exact::<T, 3>(block, buffer),
+        4 => deinterleave_exact::<T, 4>(block, buffer),
+        _ => buffer.extend(block.iter().step_by(stride).copied()),
+    }
+}
+
+#[inline]
+fn deinterleave_exact<T: Copy, const STRIDE: usize>(block: &[T], buffer: &mut Vec<T>) {
+    let steps = block.chunks_exact(STRIDE);
+    let remainder = steps.remainder();
+    buffer.extend(steps.map(|step| step[0]));
+    buffer.extend(remainder.first().copied());
+}
+
+/// Largest stride the 8-lane gather kernels support, their `i32` offsets go up
+/// to seven strides.
+#[cfg(target_arch = "x86_64")]
+pub(crate) const MAX_GATHER_STRIDE: usize = i32::MAX as usize / 8;
+
+/// Number of full 8-lane gathers over `arr[0]`, `arr[stride]`, ... and the
+/// part of `arr` holding the strided elements left after them.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) fn split_gathers<T>(arr: &[T], stride: usize) -> (usize, &[T]) {
+    let gathers = arr.len().div_ceil(stride) / 8;
+    let rem = &arr[(gathers * 8 * stride).min(arr.len())..];
+    (gathers, rem)
+}